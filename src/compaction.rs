@@ -0,0 +1,170 @@
+use alloc::boxed::Box;
+use core::fmt::{self, Debug, Formatter};
+use core::panic::{RefUnwindSafe, UnwindSafe};
+
+use crate::index_type::*;
+use crate::linked_vector::*;
+
+/// The callback a [CompactionPolicy] fires after compacting a vector. It only
+/// has to be `Send`, and the wrapper supplies the other auto traits, so
+/// `LinkedVector` has the same ones with or without a callback.
+///
+struct Callback<I>(Box<CallbackFn<I>>);
+
+type CallbackFn<I> = dyn FnMut(&HNodeRemap<I>) + Send;
+
+// The callback is only reached through `&mut`, as `FnMut` requires, so a
+// shared reference to it, which is all `Sync` hands out, can't be used to run
+// it from more than one thread.
+unsafe impl<I> Sync for Callback<I> {}
+
+// The vector never exposes the callback, and takes the policy out while it
+// runs, so a callback that panics is dropped as the panic unwinds. Nothing
+// it may have left broken can be observed through the vector afterward.
+impl<I> UnwindSafe for Callback<I> {}
+impl<I> RefUnwindSafe for Callback<I> {}
+
+/// An opt-in policy that has a `LinkedVector` compact itself in place once it
+/// becomes too fragmented, as measured by
+/// [fragmentation()](LinkedVector::fragmentation). It's checked when elements
+/// are removed by [remove()](LinkedVector::remove),
+/// [try_remove()](LinkedVector::try_remove),
+/// [pop_back()](LinkedVector::pop_back), and
+/// [pop_front()](LinkedVector::pop_front). Set it with
+/// [set_compaction_policy()](LinkedVector::set_compaction_policy). Removing
+/// elements through a cursor or a consuming iterator doesn't compact the 
+/// vector, so the cursor's position and the handles the iterator yields stay
+/// valid.
+///
+/// Compacting moves nodes, so handles held from before may no longer refer to
+/// their elements afterward. A callback set with
+/// [on_compact()](CompactionPolicy::on_compact) receives the remap that
+/// translates them.
+///
+/// The share of vacant nodes is checked on every removal, since it's cheap to
+/// compute. Measuring how many links are out of order requires traversing the
+/// list, so the full measure is only taken once every `len / 16` removals or
+/// so, which keeps removal O(1) amortized.
+/// ```
+/// use std::sync::mpsc;
+/// use linked_vector::*;
+/// let (tx, rx) = mpsc::channel();
+/// let mut lv   = LinkedVector::from_iter(0..100);
+///
+/// lv.set_compaction_policy(Some(
+///     CompactionPolicy::new(0.5)
+///         .on_compact(move |remap| tx.send(remap.len()).unwrap())));
+///
+/// // The 51st removal leaves more than half the nodes vacant.
+/// for _ in 0..51 {
+///     lv.pop_front();
+/// }
+/// assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![49]);
+/// assert_eq!(lv.vacant_count(), 0);
+/// assert_eq!(lv.to_vec(), (51..100).collect::<Vec<_>>());
+/// ```
+pub struct CompactionPolicy<I: IndexType = usize> {
+    threshold : f64,
+    min_nodes : usize,
+    countdown : usize,
+    callback  : Option<Callback<I>>,
+}
+
+impl<I: IndexType> CompactionPolicy<I> {
+    /// Creates a policy that compacts the vector once its fragmentation
+    /// exceeds `threshold`. Fragmentation ranges from 0 to 2, so a threshold
+    /// of 0.5 compacts a list that has, for instance, more vacant nodes than
+    /// occupied ones. Vectors with fewer than 64 nodes aren't compacted; see
+    /// [min_nodes()](CompactionPolicy::min_nodes).
+    ///
+    #[must_use]
+    pub fn new(threshold: f64) -> Self {
+        Self {
+            threshold,
+            min_nodes : 64,
+            countdown : 0,
+            callback  : None,
+        }
+    }
+
+    /// Sets the number of nodes the internal vector must have before it's
+    /// compacted, so small vectors aren't compacted over and over.
+    ///
+    #[must_use]
+    pub fn min_nodes(mut self, min_nodes: usize) -> Self {
+        self.min_nodes = min_nodes;
+        self
+    }
+
+    /// Sets a callback that's given the remap from each compaction, which
+    /// translates the handles from before it into handles for the same
+    /// elements afterward.
+    ///
+    /// The callback only has to be `Send`, so it can own state that isn't 
+    /// `Sync`, like a `Cell` or the sending half of a channel. The vector 
+    /// stays `Send`, `Sync`, `UnwindSafe`, and `RefUnwindSafe` regardless: it
+    /// only calls the callback through `&mut self`, never hands it out, and 
+    /// drops the policy if the callback panics.
+    ///
+    #[must_use]
+    pub fn on_compact<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&HNodeRemap<I>) + Send + 'static,
+    {
+        self.callback = Some(Callback(Box::new(callback)));
+        self
+    }
+
+    /// Returns the fragmentation above which the vector is compacted.
+    ///
+    #[inline]
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Decides, after an element is removed, whether the vector should be
+    /// compacted. `nodes` is the number of nodes in the internal vector, `len`
+    /// the length of the list, and `vacant` the share of the nodes that are 
+    /// vacant. `fragmentation` measures the vector fully, which is only done
+    /// periodically.
+    ///
+    pub(crate) fn should_compact<F>(&mut self,
+                                    nodes         : usize,
+                                    len           : usize,
+                                    vacant        : f64,
+                                    fragmentation : F) -> bool
+    where
+        F: FnOnce() -> f64,
+    {
+        if nodes < self.min_nodes {
+            return false;
+        }
+        if vacant > self.threshold {
+            return true;
+        }
+        if self.countdown > 0 {
+            self.countdown -= 1;
+            return false;
+        }
+        self.countdown = len / 16;
+        fragmentation() > self.threshold
+    }
+
+    /// Fires the callback, if there is one, with the remap from a compaction.
+    ///
+    pub(crate) fn compacted(&mut self, remap: &HNodeRemap<I>) {
+        if let Some(Callback(callback)) = &mut self.callback {
+            callback(remap);
+        }
+    }
+}
+
+impl<I: IndexType> Debug for CompactionPolicy<I> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("CompactionPolicy")
+         .field("threshold", &self.threshold)
+         .field("min_nodes", &self.min_nodes)
+         .field("callback", &self.callback.is_some())
+         .finish()
+    }
+}
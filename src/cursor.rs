@@ -1,13 +1,16 @@
+use alloc::vec::Vec;
 
 use core::ops::{Deref, DerefMut};
 
+use crate::index_type::*;
 use crate::linked_vector::*;
+use crate::storage::*;
 
 /// A cursor is a position within a linked vector. It can be used to traverse
 /// the list in either direction, and to access the element at the current
 /// position.
 /// 
-pub trait CursorBase<T> {
+pub trait CursorBase<T, I = usize> {
     /// Returns a reference to the element at the cursor's current position. If
     /// the feature, `"optionless-accessors"`, is disabled, the return type is
     /// `Option<&T>` instead of `&T`. This feature is disabled by default.
@@ -25,7 +28,7 @@ pub trait CursorBase<T> {
 
     /// Returns the handle of the element at the cursor's current position.
     /// 
-    fn node(&self) -> HNode;
+    fn node(&self) -> HNode<I>;
 
     /// Moves the cursor to the specified handle. The handle must be valid. If
     /// the feature, `"optionless-accessors"`, is disabled, the return type is
@@ -33,7 +36,7 @@ pub trait CursorBase<T> {
     /// [usage notes](./index.html#feature-optionless-accessors).
     /// 
     #[cfg(feature = "optionless-accessors")]
-    fn move_to(&mut self, handle: HNode);
+    fn move_to(&mut self, handle: HNode<I>);
 
     /// Moves the cursor to the specified handle. The handle must be valid. 
     /// Returns true if the move was successful. If the `"optionless-accessors"`
@@ -42,41 +45,69 @@ pub trait CursorBase<T> {
     /// [usage notes](./index.html#feature-optionless-accessors).
     /// 
     #[cfg(not(feature = "optionless-accessors"))]
-    fn move_to(&mut self, handle: HNode) -> bool;
+    fn move_to(&mut self, handle: HNode<I>) -> bool;
+
+    /// Moves the cursor to the specified handle, which can be any kind of 
+    /// handle accepted by `LinkedVector<T>`, such as a `TypedHNode<T>`. See
+    /// [move_to()](CursorBase::move_to). This method isn't available through
+    /// `dyn CursorBase`.
+    /// 
+    #[cfg(feature = "optionless-accessors")]
+    #[inline]
+    fn move_to_handle(&mut self, handle: impl NodeHandle<T, I>) 
+    where
+        Self: Sized,
+    {
+        self.move_to(handle.hnode())
+    }
+
+    /// Moves the cursor to the specified handle, which can be any kind of 
+    /// handle accepted by `LinkedVector<T>`, such as a `TypedHNode<T>`. See
+    /// [move_to()](CursorBase::move_to). This method isn't available through
+    /// `dyn CursorBase`.
+    /// 
+    #[cfg(not(feature = "optionless-accessors"))]
+    #[inline]
+    fn move_to_handle(&mut self, handle: impl NodeHandle<T, I>) -> bool 
+    where
+        Self: Sized,
+    {
+        self.move_to(handle.hnode())
+    }
 
     /// Moves the cursor to the next element. Returns the handle of the next
     /// element if the cursor was moved, None if the cursor was already at the
     /// end of the list.
     /// 
-    fn move_next(&mut self) -> Option<HNode>;
+    fn move_next(&mut self) -> Option<HNode<I>>;
 
     /// Moves the cursor to the previous element. Returns the handle of the
     /// previous element if the cursor was moved, None if the cursor was
     /// already at the start of the list.
     /// 
-    fn move_prev(&mut self) -> Option<HNode>;
+    fn move_prev(&mut self) -> Option<HNode<I>>;
 
     /// Moves the cursor to the end of the list. Returns the handle of the
     /// last element if the cursor was moved, None if the list is empty.
     /// 
-    fn move_to_back(&mut self) -> Option<HNode>;
+    fn move_to_back(&mut self) -> Option<HNode<I>>;
 
     /// Moves the cursor to the start of the list. Returns the handle of the
     /// first element if the cursor was moved, None if the list is empty.
     /// 
-    fn move_to_front(&mut self) -> Option<HNode>;
+    fn move_to_front(&mut self) -> Option<HNode<I>>;
 
     /// Moves the cursor to the start of the list. Returns the handle of the
     /// first element if the cursor was moved, None if the list is empty.
     /// 
     #[deprecated(since = "1.1.0", note = "Use move_to_front() instead.")]
-    fn move_to_start(&mut self) -> Option<HNode>;
+    fn move_to_start(&mut self) -> Option<HNode<I>>;
 
     /// Moves the cursor to the end of the list. Returns the handle of the
     /// last element if the cursor was moved, None if the list is empty.
     /// 
     #[deprecated(since = "1.1.0", note = "Use move_to_back() instead.")]
-    fn move_to_end(&mut self) -> Option<HNode>;
+    fn move_to_end(&mut self) -> Option<HNode<I>>;
 
     /// Moves the cursor forward by the specified number of elements. Returns
     /// the handle of the element at the new position if the cursor was moved,
@@ -84,7 +115,7 @@ pub trait CursorBase<T> {
     /// The handle at the current position after the move is returned in 
     /// either Result variant.
     /// 
-    fn forward(&mut self, n: usize) -> Result<HNode, HNode>;
+    fn forward(&mut self, n: usize) -> Result<HNode<I>, HNode<I>>;
 
     /// Moves the cursor backward by the specified number of elements. Returns
     /// the handle of the element at the new position if the cursor was moved,
@@ -92,22 +123,22 @@ pub trait CursorBase<T> {
     /// The handle at the current position after the move is returned in 
     /// either Result variant.
     /// 
-    fn backward(&mut self, n: usize) -> Result<HNode, HNode>;
+    fn backward(&mut self, n: usize) -> Result<HNode<I>, HNode<I>>;
 }
 
 /// A cursor which can only read the elements of the list.
 /// 
-pub struct Cursor<'a, T> {
-    lvec   : &'a LinkedVector<T>,
-    handle : HNode,
+pub struct Cursor<'a, T, I: IndexType = usize, S = Vec<Node<T, I>>> {
+    lvec   : &'a LinkedVector<T, I, S>,
+    handle : HNode<I>,
 }
 
-impl<'a, T> Cursor<'a, T> {
-    pub(crate) fn new(lvec   : &'a LinkedVector<T>, 
-                      handle : HNode) 
+impl<'a, T, I: IndexType, S: NodeStorage<Node<T, I>>> Cursor<'a, T, I, S> {
+    pub(crate) fn new(lvec   : &'a LinkedVector<T, I, S>, 
+                      handle : HNode<I>) 
         -> Self 
     {
-        #[cfg(debug_assertions)]
+        #[cfg(feature = "generational-handles")]
         lvec.check_handle(handle);
 
         Self {
@@ -116,7 +147,11 @@ impl<'a, T> Cursor<'a, T> {
         }
     }
 }
-impl<'a, T> CursorBase<T> for Cursor<'a, T> {
+impl<'a, T, I, S> CursorBase<T, I> for Cursor<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     #[cfg(feature = "optionless-accessors")]
     fn get(&self) -> &T {
         self.lvec.get(self.handle)
@@ -131,21 +166,21 @@ impl<'a, T> CursorBase<T> for Cursor<'a, T> {
         }
     }
 
-    fn node(&self) -> HNode {
+    fn node(&self) -> HNode<I> {
         self.handle
     }
 
     #[cfg(feature = "optionless-accessors")]
-    fn move_to(&mut self, handle: HNode) {
-        #[cfg(debug_assertions)]
+    fn move_to(&mut self, handle: HNode<I>) {
+        #[cfg(feature = "generational-handles")]
         self.lvec.check_handle(handle);
 
         self.handle = handle;
     }
 
     #[cfg(not(feature = "optionless-accessors"))]
-    fn move_to(&mut self, handle: HNode) -> bool {
-        #[cfg(debug_assertions)]
+    fn move_to(&mut self, handle: HNode<I>) -> bool {
+        #[cfg(feature = "generational-handles")]
         self.lvec.check_handle(handle);
         
         if self.lvec.is_empty() {
@@ -156,42 +191,42 @@ impl<'a, T> CursorBase<T> for Cursor<'a, T> {
         }
     }
 
-    fn move_next(&mut self) -> Option<HNode> {
+    fn move_next(&mut self) -> Option<HNode<I>> {
         self.lvec.next_node(self.handle).map(|hnext| {
             self.handle = hnext;
             hnext
         })
     }
 
-    fn move_prev(&mut self) -> Option<HNode> {
+    fn move_prev(&mut self) -> Option<HNode<I>> {
         self.lvec.prev_node(self.handle).map(|hprev| {
             self.handle = hprev;
             hprev
         })
     }
 
-    fn move_to_front(&mut self) -> Option<HNode> {
+    fn move_to_front(&mut self) -> Option<HNode<I>> {
         self.lvec.front_node().map(|hstart| {
             self.handle = hstart;
             hstart
         })
     }
 
-    fn move_to_back(&mut self) -> Option<HNode> {
+    fn move_to_back(&mut self) -> Option<HNode<I>> {
         self.lvec.back_node().map(|hend| {
             self.handle = hend;
             hend
         })
     }
 
-    fn move_to_start(&mut self) -> Option<HNode> {
+    fn move_to_start(&mut self) -> Option<HNode<I>> {
         self.move_to_front()
     }
 
-    fn move_to_end(&mut self) -> Option<HNode> {
+    fn move_to_end(&mut self) -> Option<HNode<I>> {
         self.move_to_back()
     }
-    fn forward(&mut self, n: usize) ->Result<HNode, HNode> {
+    fn forward(&mut self, n: usize) ->Result<HNode<I>, HNode<I>> {
         for _ in 0..n {
             if self.move_next().is_none() {
                 return Err(self.handle);
@@ -199,7 +234,7 @@ impl<'a, T> CursorBase<T> for Cursor<'a, T> {
         }
         Ok(self.handle)
     }
-    fn backward(&mut self, n: usize) -> Result<HNode, HNode> {
+    fn backward(&mut self, n: usize) -> Result<HNode<I>, HNode<I>> {
         for _ in 0..n {
             if self.move_prev().is_none() {
                 return Err(self.handle);
@@ -209,7 +244,11 @@ impl<'a, T> CursorBase<T> for Cursor<'a, T> {
     }
 }
 
-impl<'a, T> Deref for Cursor<'a, T> {
+impl<'a, T, I, S> Deref for Cursor<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -226,18 +265,18 @@ impl<'a, T> Deref for Cursor<'a, T> {
 /// position of the cursor. This feature is turned on with the `"cursor-remove"`
 /// feature, see [usage notes](./index.html#feature-cursor-remove).
 /// 
-pub struct CursorMut<'a, T> {
-    lvec   : &'a mut LinkedVector<T>,
-    handle : HNode,
+pub struct CursorMut<'a, T, I: IndexType = usize, S = Vec<Node<T, I>>> {
+    lvec   : &'a mut LinkedVector<T, I, S>,
+    handle : HNode<I>,
 }
 
-impl<'a, T> CursorMut<'a, T> {
+impl<'a, T, I: IndexType, S: NodeStorage<Node<T, I>>> CursorMut<'a, T, I, S> {
 
-    pub(crate) fn new(lvec   : &'a mut LinkedVector<T>, 
-                      handle : HNode) 
+    pub(crate) fn new(lvec   : &'a mut LinkedVector<T, I, S>, 
+                      handle : HNode<I>) 
         -> Self 
     {
-        #[cfg(debug_assertions)]
+        #[cfg(feature = "generational-handles")]
         lvec.check_handle(handle);
 
         Self {
@@ -285,7 +324,7 @@ impl<'a, T> CursorMut<'a, T> {
     /// will be moved to the new element. Returns the handle of the new
     /// element.
     /// 
-    pub fn insert(&mut self, value: T) -> HNode {
+    pub fn insert(&mut self, value: T) -> HNode<I> {
         self.handle = self.lvec.insert(self.handle, value);
         self.handle
     }
@@ -294,15 +333,17 @@ impl<'a, T> CursorMut<'a, T> {
     /// will still be at the same position. Returns the handle of the new
     /// element.
     /// 
-    pub fn insert_after(&mut self, value: T) -> HNode {
+    pub fn insert_after(&mut self, value: T) -> HNode<I> {
         self.lvec.insert_after(self.handle, value)
     }
 
     /// Removes the element at the current position and returns its value. The 
     /// cursor will be moved to the next element if not at the end of the 
     /// vector, otherwise it moves to the new end. If the vector is already 
-    /// empty, `None` is returned. The `"cursor-remove"` feature must be
-    /// enabled to use this method, see 
+    /// empty, `None` is returned. The vector's 
+    /// [CompactionPolicy](crate::CompactionPolicy), if set, isn't applied, so
+    /// the cursor's position stays valid. The `"cursor-remove"` feature must 
+    /// be enabled to use this method, see 
     /// [usage notes](./index.html#feature-cursor-remove).
     /// 
     #[cfg(feature = "cursor-remove")]
@@ -316,18 +357,18 @@ impl<'a, T> CursorMut<'a, T> {
             } else if let Some(hprev) = self.lvec.prev_node(self.handle) {
                 self.handle = hprev;
             } else {
-                self.handle = BAD_HANDLE;
+                self.handle = HNode::BAD;
             }
-            #[cfg(not(feature = "optionless-accessors"))]
-            { self.lvec.remove(hrem) }
-
-            #[cfg(feature = "optionless-accessors")]
-            { Some(self.lvec.remove(hrem)) }
+            self.lvec.remove_(Some(hrem))
         }
     }
 }
 
-impl<'a, T> CursorBase<T> for CursorMut<'a, T> {
+impl<'a, T, I, S> CursorBase<T, I> for CursorMut<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     #[cfg(feature = "optionless-accessors")]
     fn get(&self) -> &T {
         self.lvec.get(self.handle)
@@ -342,21 +383,21 @@ impl<'a, T> CursorBase<T> for CursorMut<'a, T> {
         }
     }
 
-    fn node(&self) -> HNode {
+    fn node(&self) -> HNode<I> {
         self.handle
     }
 
     #[cfg(feature = "optionless-accessors")]
-    fn move_to(&mut self, handle: HNode) {
-        #[cfg(debug_assertions)]
+    fn move_to(&mut self, handle: HNode<I>) {
+        #[cfg(feature = "generational-handles")]
         self.lvec.check_handle(handle);
         
         self.handle = handle;
     }
 
     #[cfg(not(feature = "optionless-accessors"))]
-    fn move_to(&mut self, handle: HNode) -> bool {
-        #[cfg(debug_assertions)]
+    fn move_to(&mut self, handle: HNode<I>) -> bool {
+        #[cfg(feature = "generational-handles")]
         self.lvec.check_handle(handle);
         
         if self.lvec.is_empty() {
@@ -367,43 +408,43 @@ impl<'a, T> CursorBase<T> for CursorMut<'a, T> {
         }
     }
 
-    fn move_next(&mut self) -> Option<HNode> {
+    fn move_next(&mut self) -> Option<HNode<I>> {
         self.lvec.next_node(self.handle).map(|hnext| {
             self.handle = hnext;
             hnext
         })
     }
 
-    fn move_prev(&mut self) -> Option<HNode> {
+    fn move_prev(&mut self) -> Option<HNode<I>> {
         self.lvec.prev_node(self.handle).map(|hprev| {
             self.handle = hprev;
             hprev
         })
     }
 
-    fn move_to_front(&mut self) -> Option<HNode> {
+    fn move_to_front(&mut self) -> Option<HNode<I>> {
         self.lvec.front_node().map(|hstart| {
             self.handle = hstart;
             hstart
         })
     }
 
-    fn move_to_back(&mut self) -> Option<HNode> {
+    fn move_to_back(&mut self) -> Option<HNode<I>> {
         self.lvec.back_node().map(|hend| {
             self.handle = hend;
             hend
         })
     }
 
-    fn move_to_start(&mut self) -> Option<HNode> {
+    fn move_to_start(&mut self) -> Option<HNode<I>> {
         self.move_to_front()
     }
 
-    fn move_to_end(&mut self) -> Option<HNode> {
+    fn move_to_end(&mut self) -> Option<HNode<I>> {
         self.move_to_back()
     }
 
-    fn forward(&mut self, n: usize) -> Result<HNode, HNode> {
+    fn forward(&mut self, n: usize) -> Result<HNode<I>, HNode<I>> {
         for _ in 0..n {
             if self.move_next().is_none() {
                 return Err(self.handle);
@@ -412,7 +453,7 @@ impl<'a, T> CursorBase<T> for CursorMut<'a, T> {
         Ok(self.handle)
     }
 
-    fn backward(&mut self, n: usize) -> Result<HNode, HNode> {
+    fn backward(&mut self, n: usize) -> Result<HNode<I>, HNode<I>> {
         for _ in 0..n {
             if self.move_prev().is_none() {
                 return Err(self.handle);
@@ -422,7 +463,11 @@ impl<'a, T> CursorBase<T> for CursorMut<'a, T> {
     }
 }
 
-impl<'a, T> Deref for CursorMut<'a, T> {
+impl<'a, T, I, S> Deref for CursorMut<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -434,7 +479,11 @@ impl<'a, T> Deref for CursorMut<'a, T> {
     }
 }
 
-impl<'a, T> DerefMut for CursorMut<'a, T> {
+impl<'a, T, I, S> DerefMut for CursorMut<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     fn deref_mut(&mut self) -> &mut Self::Target {
         #[cfg(feature = "optionless-accessors")]
         { self.get_mut() }
@@ -0,0 +1,207 @@
+use alloc::collections::TryReserveError;
+use core::fmt::{self, Display, Formatter};
+
+/// The error type returned by the fallible methods of `LinkedVector`, such as
+/// `try_get()` and `try_remove()`. Describes why a handle couldn't be used with
+/// the vector it was passed to, or why room couldn't be made for a new element.
+/// 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LinkedVectorError {
+    /// The handle doesn't refer to a node within the vector. Default handles
+    /// are always invalid.
+    InvalidHandle,
+
+    /// The handle was issued by a different vector. Foreign handles are only
+    /// detected with the `"checked-handles"` feature.
+    ForeignHandle,
+
+    /// The node the handle referred to has been removed from the vector. With
+    /// the `"generational-handles"` feature, this is also detected after the
+    /// node has been recycled for another element.
+    ExpiredHandle,
+
+    /// The operation requires the vector to have at least one element.
+    EmptyList,
+
+    /// Room for a new element couldn't be reserved, either because allocation
+    /// failed or because the storage of the vector is full. A 
+    /// `TryReserveError` can only be made outside the standard library by a
+    /// failed reservation, so full `ArrayStorage`, and any allocation failure
+    /// of the `allocator-api2` storage, is reported as a capacity overflow,
+    /// the same kind as asking for more than `isize::MAX` bytes. Comparing
+    /// `len()` with `capacity()` tells a full array apart.
+    ReserveFailed(TryReserveError),
+}
+
+impl From<TryReserveError> for LinkedVectorError {
+    #[inline]
+    fn from(error: TryReserveError) -> Self {
+        Self::ReserveFailed(error)
+    }
+}
+
+impl Display for LinkedVectorError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidHandle => write!(f, "Handle is invalid."),
+            Self::ForeignHandle => write!(f, "Handle is not native."),
+            Self::ExpiredHandle => write!(f, "Handle has expired."),
+            Self::EmptyList     => write!(f, "The vector is empty."),
+            Self::ReserveFailed(e) => write!(f, "Couldn't reserve room: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LinkedVectorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ReserveFailed(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// The error type returned by `try_push_back()` and `try_push_front()` of 
+/// `LinkedVector` when room couldn't be made for a new element. It holds the 
+/// element, so it isn't lost, along with the error from the reservation.
+/// ```
+/// use linked_vector::*;
+/// let mut lv = ArrayLinkedVector::<String, 1>::new_array();
+/// lv.push_back("one".to_string());
+/// 
+/// let err = lv.try_push_back("two".to_string()).unwrap_err();
+/// 
+/// assert_eq!(err.into_inner(), "two");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryPushError<T> {
+    value : T,
+    error : TryReserveError,
+}
+
+impl<T> TryPushError<T> {
+    #[inline]
+    pub(crate) fn new(value: T, error: TryReserveError) -> Self {
+        Self { value, error }
+    }
+
+    /// Returns the error from the reservation.
+    /// 
+    #[inline]
+    pub fn error(&self) -> &TryReserveError {
+        &self.error
+    }
+
+    /// Returns the element that couldn't be added, consuming the error.
+    /// 
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns the element that couldn't be added and the error from the
+    /// reservation, consuming the error.
+    /// 
+    #[inline]
+    pub fn into_parts(self) -> (T, TryReserveError) {
+        (self.value, self.error)
+    }
+}
+
+impl<T> From<TryPushError<T>> for LinkedVectorError {
+    #[inline]
+    fn from(error: TryPushError<T>) -> Self {
+        Self::ReserveFailed(error.error)
+    }
+}
+
+impl<T> Display for TryPushError<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Couldn't reserve room for the element: {}", self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for TryPushError<T> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The error type returned by `try_insert()` and `try_insert_after()` of 
+/// `LinkedVector` when the handle can't be used with the vector, or room 
+/// couldn't be made for the new element. It holds the element, so it isn't 
+/// lost, along with the `LinkedVectorError` describing the failure.
+/// ```
+/// use linked_vector::*;
+/// let mut lv = ArrayLinkedVector::<String, 1>::new_array();
+/// let h1 = lv.push_back("one".to_string());
+/// 
+/// let err = lv.try_insert(h1, "two".to_string()).unwrap_err();
+/// 
+/// assert!(matches!(err.error(), LinkedVectorError::ReserveFailed(_)));
+/// assert_eq!(err.into_inner(), "two");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryInsertError<T> {
+    value : T,
+    error : LinkedVectorError,
+}
+
+impl<T> TryInsertError<T> {
+    #[inline]
+    pub(crate) fn new(value: T, error: LinkedVectorError) -> Self {
+        Self { value, error }
+    }
+
+    /// Returns the error describing why the element couldn't be inserted.
+    /// 
+    #[inline]
+    pub fn error(&self) -> &LinkedVectorError {
+        &self.error
+    }
+
+    /// Returns the element that couldn't be inserted, consuming the error.
+    /// 
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns the element that couldn't be inserted and the error describing
+    /// why, consuming the error.
+    /// 
+    #[inline]
+    pub fn into_parts(self) -> (T, LinkedVectorError) {
+        (self.value, self.error)
+    }
+}
+
+impl<T> From<TryPushError<T>> for TryInsertError<T> {
+    #[inline]
+    fn from(error: TryPushError<T>) -> Self {
+        Self::new(error.value, LinkedVectorError::ReserveFailed(error.error))
+    }
+}
+
+impl<T> From<TryInsertError<T>> for LinkedVectorError {
+    #[inline]
+    fn from(error: TryInsertError<T>) -> Self {
+        error.error
+    }
+}
+
+impl<T> Display for TryInsertError<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Couldn't insert the element: {}", self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for TryInsertError<T> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
@@ -0,0 +1,88 @@
+use core::fmt::Debug;
+use core::hash::Hash;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for usize {}
+}
+
+/// The integer type a `LinkedVector` uses to index the nodes of its internal
+/// vector. The links between nodes, and the handles to them, are stored with
+/// this type, so a narrower type shrinks each node. `u16`, `u32`, and `usize`
+/// are supported; `usize` is the default.
+///
+/// The largest value of the type is reserved to mark the absence of a node, so
+/// a `LinkedVector<T, u16>` can hold at most `u16::MAX` nodes, including
+/// vacant ones. Adding a node beyond that panics.
+///
+/// The generations kept by the `"generational-handles"` feature are stored 
+/// with this type as well. They wrap around, so with `u16`, a handle to a node
+/// that has been recycled a multiple of 65536 times isn't detected as expired.
+/// ```
+/// use linked_vector::*;
+/// let mut lv = LinkedVector::<u32, u32>::new_indexed();
+/// let h1 = lv.push_back(1);
+/// let h2 = lv.push_back(2);
+///
+/// assert_eq!(lv[h1], 1);
+/// assert_eq!(lv.to_vec(), vec![1, 2]);
+/// ```
+pub trait IndexType: Copy + Debug + Eq + Hash + Ord + private::Sealed {
+    /// The value reserved to mark the absence of a node.
+    ///
+    const NONE: Self;
+
+    /// Converts an index of the internal vector into this type. Panics if the
+    /// index doesn't fit.
+    ///
+    fn from_usize(index: usize) -> Self;
+
+    /// Converts the value into an index of the internal vector.
+    ///
+    fn index(self) -> usize;
+
+    /// Converts a generation into this type, or returns `None` if it doesn't
+    /// fit. Unlike an index, a generation can take the value of `NONE`.
+    ///
+    fn gen_from_usize(gen: usize) -> Option<Self>;
+
+    /// Returns the generation that follows this one, wrapping around to zero
+    /// after the largest value of the type.
+    ///
+    fn next_gen(self) -> Self;
+}
+
+macro_rules! impl_index_type {
+    ($($t:ty),*) => {$(
+        impl IndexType for $t {
+            const NONE: Self = <$t>::MAX;
+
+            #[inline(always)]
+            fn from_usize(index: usize) -> Self {
+                assert!(index < Self::NONE as usize,
+                        "Index exceeds the capacity of the index type.");
+                index as $t
+            }
+
+            #[inline(always)]
+            fn index(self) -> usize {
+                self as usize
+            }
+
+            #[inline(always)]
+            fn gen_from_usize(gen: usize) -> Option<Self> {
+                <$t>::try_from(gen).ok()
+            }
+
+            #[inline(always)]
+            fn next_gen(self) -> Self {
+                self.wrapping_add(1)
+            }
+        }
+    )*};
+}
+
+impl_index_type!(u16, u32, usize);
@@ -1,14 +1,39 @@
 #![doc = "To Primary Struct: [LinkedVector]"]
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+// The accessors returning borrowing types like `Cursor<T>` elide the lifetime,
+// which is linted by newer compilers than the API was written for.
+#![allow(unknown_lints, mismatched_lifetime_syntaxes)]
 
+extern crate alloc;
+
+pub use crate::compaction::*;
 pub use crate::cursor::*;
+pub use crate::error::*;
+pub use crate::index_type::*;
 pub use crate::linked_vector::*;
+pub use crate::recycle::*;
+pub use crate::storage::*;
+
+#[cfg(feature = "serde")]
+pub use crate::serialize::layout;
 
+mod compaction;
 mod cursor;
+mod error;
+mod index_type;
 mod linked_vector;
+mod recycle;
+mod storage;
+
+#[cfg(feature = "serde")]
+mod serialize;
 
 #[cfg(test)]
 mod tests_linked_vector;
 
 #[cfg(test)]
 mod tests_cursor;
+
+#[cfg(all(test, feature = "serde"))]
+mod tests_serialize;
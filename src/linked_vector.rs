@@ -4,90 +4,495 @@ use core::cmp::Ordering;
 use core::fmt::{self, Debug, Formatter};
 use core::hash::{Hash, Hasher};
 use core::iter::{FromIterator, FusedIterator};
-use core::ops::{Index, IndexMut};
+use core::marker::PhantomData;
+use core::mem::{self, MaybeUninit};
+use core::ops::{Bound, Index, IndexMut, RangeBounds};
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo};
+use core::ops::RangeToInclusive;
 
+use alloc::boxed::Box;
+use alloc::collections::TryReserveError;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::compaction::*;
 use crate::cursor::*;
+use crate::error::*;
+use crate::index_type::*;
+use crate::recycle::*;
+use crate::storage::*;
+
+#[cfg(feature = "allocator-api2")]
+use allocator_api2::alloc::Allocator;
+
+#[cfg(feature = "checked-handles")]
+use core::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+// The source of the IDs that tell vectors apart. Each vector takes the next
+// value when it's created, so no randomness is needed to identify it.
+#[cfg(feature = "checked-handles")]
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
 
-#[cfg(debug_assertions)]
-use uuid::{uuid, Uuid};
+/// Takes the ID for a new vector.
+/// 
+#[cfg(all(feature = "checked-handles", target_has_atomic = "ptr"))]
+#[inline]
+fn next_id() -> usize {
+    NEXT_ID.fetch_add(1, Relaxed)
+}
+
+/// Takes the ID for a new vector on targets that can load and store atomically
+/// but lack atomic read-modify-write operations, such as `thumbv6m`. These are
+/// single-core, so the ID can only be taken twice if an interrupt handler 
+/// creates a vector between the load and the store, in which case foreign 
+/// handles may go undetected between the two vectors.
+/// 
+#[cfg(all(feature = "checked-handles", not(target_has_atomic = "ptr")))]
+#[inline]
+fn next_id() -> usize {
+    let id = NEXT_ID.load(Relaxed);
+    NEXT_ID.store(id.wrapping_add(1), Relaxed);
+    id
+}
 
-#[cfg(not(debug_assertions))]
-pub(crate) const BAD_HANDLE : HNode = HNode(usize::MAX);
+/// Keeps the IDs given to vectors created from now on above `id`, which was 
+/// restored from elsewhere, such as a vector serialized by another process.
+/// 
+#[cfg(all(feature = "checked-handles", feature = "serde"))]
+pub(crate) fn reserve_id(id: usize) {
+    #[cfg(target_has_atomic = "ptr")]
+    NEXT_ID.fetch_max(id.saturating_add(1), Relaxed);
 
-#[cfg(debug_assertions)]
-pub(crate) const BAD_HANDLE : HNode = 
-                    HNode(usize::MAX, 
-                        usize::MAX, 
-                        uuid!("deadbeef-dead-beef-dead-beefdeadbeef"));
+    #[cfg(not(target_has_atomic = "ptr"))]
+    if NEXT_ID.load(Relaxed) <= id {
+        NEXT_ID.store(id.saturating_add(1), Relaxed);
+    }
+}
 
 /// A handle to a node within a `LinkedVector`. Internally, it holds an index
 /// into the vector holding the LinkedVector's nodes.
 /// 
-#[cfg(not(debug_assertions))]
+#[cfg(not(feature = "generational-handles"))]
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct HNode(usize);
+pub struct HNode<I = usize>(pub(crate) I);
 
 /// A handle to a node within a `LinkedVector`. Internally, it holds an index
-/// into the vector holding the LinkedVector's nodes.
+/// into the vector holding the LinkedVector's nodes, and the generation of the
+/// node it was issued for.
+/// 
+#[cfg(all(feature = "generational-handles", 
+          not(feature = "checked-handles")))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HNode<I = usize>(pub(crate) I, pub(crate) I);
+
+/// A handle to a node within a `LinkedVector`. Internally, it holds an index
+/// into the vector holding the LinkedVector's nodes, the generation of the node
+/// it was issued for, and the ID of the vector it belongs to.
 /// 
-#[cfg(debug_assertions)]
+#[cfg(feature = "checked-handles")]
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct HNode(usize, usize, Uuid);
+pub struct HNode<I = usize>(pub(crate) I, pub(crate) I, pub(crate) usize);
+
+impl<I: IndexType> Default for HNode<I> {
+    #[inline]
+    fn default() -> Self {
+        HNode::BAD
+    }
+}
+
+impl<I: IndexType> HNode<I> {
+    // The handle that refers to no node. It's the default handle, and marks 
+    // the ends of the list and the recycle list.
+    #[cfg(not(feature = "generational-handles"))]
+    pub(crate) const BAD: Self = HNode(I::NONE);
+
+    #[cfg(all(feature = "generational-handles", 
+              not(feature = "checked-handles")))]
+    pub(crate) const BAD: Self = HNode(I::NONE, I::NONE);
+
+    #[cfg(feature = "checked-handles")]
+    pub(crate) const BAD: Self = HNode(I::NONE, I::NONE, usize::MAX);
+
+    /// Converts the handle into a `u64` that can be stored in a database, 
+    /// passed across FFI, or used as an integer key. The index of the node is
+    /// held in the low 32 bits, and the low 32 bits of its generation in the 
+    /// high 32 bits (`0` without the `"generational-handles"` feature). The
+    /// ID of the vector isn't included, so use 
+    /// [handle_from_raw()](LinkedVector::handle_from_raw) to turn the value
+    /// back into a handle for the vector it came from. The default handle is
+    /// converted to `u64::MAX`.
+    /// 
+    /// Two limits follow from the format. With `usize` as the index type, the
+    /// generation is cut to 32 bits, so once the node has been recycled a 
+    /// multiple of 2^32 more times, the value is taken for a handle to the 
+    /// element that then holds it. And since the value doesn't name its 
+    /// vector, another vector accepts it if it has an element at the same 
+    /// node with a matching generation.
+    /// 
+    /// Panics if the index of the node doesn't fit in 32 bits.
+    /// ```
+    /// use linked_vector::*;
+    /// let lv = LinkedVector::from([1, 2, 3]);
+    /// let h2 = lv.handle(1).unwrap();
+    /// 
+    /// let raw = h2.into_raw();
+    /// 
+    /// assert_eq!(lv.handle_from_raw(raw), Ok(h2));
+    /// assert_eq!(<HNode>::default().into_raw(), u64::MAX);
+    /// ```
+    #[inline]
+    pub fn into_raw(self) -> u64 {
+        if self == HNode::BAD {
+            return u64::MAX;
+        }
+        let index = self.0.index();
+        assert!(index < u32::MAX as usize, "Handle index exceeds 32 bits.");
+
+        #[cfg(feature = "generational-handles")]
+        { (self.1.index() as u32 as u64) << 32 | index as u64 }
+
+        #[cfg(not(feature = "generational-handles"))]
+        { index as u64 }
+    }
+}
+
+/// A handle to a node within a `LinkedVector<T>` that is bound to the element
+/// type of the vector. It's accepted wherever an `HNode` is, but only by 
+/// vectors of the same element type, so passing a handle to a vector of the
+/// wrong type is caught at compile time. It has the same size as `HNode`.
+/// ```
+/// use linked_vector::*;
+/// let mut lv = LinkedVector::from([1, 2, 3]);
+/// let h4: TypedHNode<i32> = lv.push_back(4).into();
+/// 
+/// assert_eq!(lv[h4], 4);
+/// 
+/// lv.remove(h4);
+/// 
+/// assert_eq!(lv.to_vec(), vec![1, 2, 3]);
+/// ```
+/// ```compile_fail
+/// use linked_vector::*;
+/// let mut lv1 = LinkedVector::from([1, 2, 3]);
+/// let mut lv2 = LinkedVector::from(["a", "b", "c"]);
+/// let h4: TypedHNode<i32> = lv1.push_back(4).into();
+/// 
+/// lv2.remove(h4);
+/// ```
+#[repr(transparent)]
+pub struct TypedHNode<T, I = usize> {
+    hnode   : HNode<I>,
+    _marker : PhantomData<fn() -> T>,
+}
+
+impl<T, I: IndexType> TypedHNode<T, I> {
+    /// Binds the handle to the element type `T`. The handle should have been
+    /// issued by a `LinkedVector<T>`.
+    /// 
+    #[inline]
+    pub fn new(hnode: HNode<I>) -> Self {
+        Self { hnode, _marker: PhantomData }
+    }
+}
+
+impl<T, I: IndexType> Clone for TypedHNode<T, I> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, I: IndexType> Copy for TypedHNode<T, I> {}
+
+impl<T, I: IndexType> Debug for TypedHNode<T, I> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("TypedHNode").field(&self.hnode).finish()
+    }
+}
 
-impl Default for HNode {
+impl<T, I: IndexType> Default for TypedHNode<T, I> {
     #[inline]
     fn default() -> Self {
-        BAD_HANDLE
+        Self::new(HNode::BAD)
+    }
+}
+
+impl<T, I: IndexType> From<HNode<I>> for TypedHNode<T, I> {
+    #[inline]
+    fn from(hnode: HNode<I>) -> Self {
+        Self::new(hnode)
+    }
+}
+
+impl<T, I: IndexType> From<TypedHNode<T, I>> for HNode<I> {
+    #[inline]
+    fn from(node: TypedHNode<T, I>) -> Self {
+        node.hnode
+    }
+}
+
+impl<T, I: IndexType> PartialEq for TypedHNode<T, I> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hnode == other.hnode
+    }
+}
+
+impl<T, I: IndexType> PartialEq<HNode<I>> for TypedHNode<T, I> {
+    #[inline]
+    fn eq(&self, other: &HNode<I>) -> bool {
+        self.hnode == *other
+    }
+}
+
+/// The handle types accepted by the methods of `LinkedVector<T>` and its 
+/// cursors: `HNode`, which can be used with a vector of any element type, and
+/// `TypedHNode<T>`, which can only be used with a vector of element type `T`.
+/// 
+pub trait NodeHandle<T, I = usize>: Copy {
+    /// Returns the untyped handle.
+    /// 
+    fn hnode(self) -> HNode<I>;
+}
+
+impl<T, I: IndexType> NodeHandle<T, I> for HNode<I> {
+    #[inline(always)]
+    fn hnode(self) -> HNode<I> {
+        self
+    }
+}
+
+impl<T, I: IndexType> NodeHandle<T, I> for TypedHNode<T, I> {
+    #[inline(always)]
+    fn hnode(self) -> HNode<I> {
+        self.hnode
+    }
+}
+
+/// The ranges of handles accepted by the methods of `LinkedVector<T>` that 
+/// operate on a range of elements, such as 
+/// [iter_range()](LinkedVector::iter_range) and [drain()](LinkedVector::drain).
+/// It's implemented for the ranges of any handle type that implements 
+/// [NodeHandle], like `h1..h2`, `h1..=h2`, `h1..`, `..h2`, and `..=h2`, for 
+/// pairs of `Bound`s of them, and for `..`.
+/// ```
+/// use linked_vector::*;
+/// let lv = LinkedVector::from([1, 2, 3, 4]);
+/// let h2: TypedHNode<i32> = lv.handle(1).unwrap().into();
+/// let h3 = lv.handle(2).unwrap();
+/// 
+/// assert_eq!(lv.iter_range(h2..).collect::<Vec<_>>(), vec![&2, &3, &4]);
+/// assert_eq!(lv.iter_range(..=h3).collect::<Vec<_>>(), vec![&1, &2, &3]);
+/// ```
+pub trait HandleRange<T, I = usize> {
+    /// Returns the start and end bounds of the range as untyped handles.
+    /// 
+    fn bounds(&self) -> (Bound<HNode<I>>, Bound<HNode<I>>);
+}
+
+/// Converts a bound on a handle of any type into a bound on an untyped handle.
+/// 
+#[inline(always)]
+fn hbound<T, I, H>(bound: Bound<&H>) -> Bound<HNode<I>> 
+where
+    H: NodeHandle<T, I>,
+{
+    match bound {
+        Bound::Included(&h) => Bound::Included(h.hnode()),
+        Bound::Excluded(&h) => Bound::Excluded(h.hnode()),
+        Bound::Unbounded    => Bound::Unbounded,
+    }
+}
+
+macro_rules! impl_handle_range {
+    ($($range:ty),*) => {
+        $(
+            impl<T, I, H> HandleRange<T, I> for $range 
+            where
+                H: NodeHandle<T, I>,
+            {
+                #[inline]
+                fn bounds(&self) -> (Bound<HNode<I>>, Bound<HNode<I>>) {
+                    (hbound(self.start_bound()), hbound(self.end_bound()))
+                }
+            }
+        )*
+    };
+}
+
+impl_handle_range!(Range<H>, RangeInclusive<H>, RangeFrom<H>, RangeTo<H>, 
+                   RangeToInclusive<H>, (Bound<H>, Bound<H>));
+
+impl<T, I> HandleRange<T, I> for RangeFull {
+    #[inline]
+    fn bounds(&self) -> (Bound<HNode<I>>, Bound<HNode<I>>) {
+        (Bound::Unbounded, Bound::Unbounded)
     }
 }
 
 /// The node type used by `LinkedVector`. It holds a value of type `T`, and 
-/// handles to the next and previous nodes in the list.
+/// the indices of the next and previous nodes in the list.
+/// 
+/// Whether the node holds a value is encoded in its previous link. Nodes in 
+/// the list always have one, since the head links back to the tail, while 
+/// vacant nodes in the recycle list have none. The value is only initialized
+/// while the node is occupied, which spares the node the discriminant of an
+/// `Option<T>`.
 /// 
-pub(crate) struct Node<T> {
-    value : Option<T>,
-    next  : HNode,
-    prev  : HNode,
-
-    // This field is used to detect expired handles. In debug mode if
-    // a handle's 2nd field doesn't match this, it's expried. When
-    // a node is added to the recycle list via. `push_recyc()`, this 
-    // number is incremented.
-    #[cfg(debug_assertions)]
-    gen   : usize,
-}
-impl<T> Node<T> {
-    #[cfg(debug_assertions)]
-    #[inline]
-    fn new(value: T, gen: usize) -> Self {
+/// The fields of a node are private. The type is public so that the storage 
+/// of a `LinkedVector` can be named, as in `Vec<Node<T>>`, and so storage can
+/// be implemented for it through [NodeStorage].
+/// 
+pub struct Node<T, I: IndexType = usize> {
+    pub(crate) value : MaybeUninit<T>,
+    pub(crate) next  : I,
+    pub(crate) prev  : I,
+
+    // This field is used to detect expired handles. With the 
+    // "generational-handles" feature, if a handle's 2nd field doesn't
+    // match this, it's expried. When a node is added to the recycle list
+    // via. `push_recyc()`, this number is incremented.
+    #[cfg(feature = "generational-handles")]
+    pub(crate) gen   : I,
+}
+impl<T, I: IndexType> Node<T, I> {
+    // Until the new node is linked into the list, its previous link refers to
+    // its own index so that it reads as occupied.
+    #[cfg(feature = "generational-handles")]
+    #[inline]
+    fn new(value: T, index: I, gen: I) -> Self {
         Self { 
-            value : Some(value), 
-            next  : BAD_HANDLE, 
-            prev  : BAD_HANDLE, 
+            value : MaybeUninit::new(value), 
+            next  : I::NONE, 
+            prev  : index, 
             gen,
         }
     }
-    #[cfg(not(debug_assertions))]
+    #[cfg(not(feature = "generational-handles"))]
     #[inline]
-    fn new(value: T) -> Self {
+    fn new(value: T, index: I) -> Self {
         Self { 
-            value : Some(value), 
-            next  : BAD_HANDLE, 
-            prev  : BAD_HANDLE, 
+            value : MaybeUninit::new(value), 
+            next  : I::NONE, 
+            prev  : index, 
+        }
+    }
+
+    /// Returns `true` if the node holds a value.
+    /// 
+    #[inline(always)]
+    pub(crate) fn is_occupied(&self) -> bool {
+        self.prev != I::NONE
+    }
+
+    /// Returns a reference to the value of the node, or `None` if it's vacant.
+    /// 
+    #[inline(always)]
+    pub(crate) fn value(&self) -> Option<&T> {
+        if self.is_occupied() {
+            // SAFETY: The value of an occupied node is initialized.
+            Some(unsafe { self.value.assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the value of the node, or `None` if it's
+    /// vacant.
+    /// 
+    #[inline(always)]
+    pub(crate) fn value_mut(&mut self) -> Option<&mut T> {
+        if self.is_occupied() {
+            // SAFETY: The value of an occupied node is initialized.
+            Some(unsafe { self.value.assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Moves the node out, leaving a vacant node with the same next link and
+    /// generation in its place.
+    /// 
+    #[inline]
+    pub(crate) fn move_out(&mut self) -> Self {
+        let vacant = Self { 
+            value : MaybeUninit::uninit(), 
+            next  : self.next, 
+            prev  : I::NONE, 
+
+            #[cfg(feature = "generational-handles")]
+            gen   : self.gen,
+        };
+        mem::replace(self, vacant)
+    }
+
+    /// Moves the value out of the node and marks it vacant by clearing its 
+    /// previous link. Returns `None` if the node is already vacant.
+    /// 
+    #[inline(always)]
+    pub(crate) fn take(&mut self) -> Option<T> {
+        if self.is_occupied() {
+            self.prev = I::NONE;
+            // SAFETY: The value was initialized, and now that the node is 
+            // marked vacant, it won't be read or dropped again.
+            Some(unsafe { self.value.assume_init_read() })
+        } else {
+            None
         }
     }
 
     #[cfg(test)]
     #[inline(always)]
-    pub(crate) fn next(&self) -> HNode {
-        self.next
+    pub(crate) fn next(&self) -> Link<I> {
+        Link(self.next)
     }
 
     #[cfg(test)]
     #[inline(always)]
-    pub(crate) fn prev(&self) -> HNode {
-        self.prev
+    pub(crate) fn prev(&self) -> Link<I> {
+        Link(self.prev)
+    }
+}
+
+impl<T, I: IndexType> Drop for Node<T, I> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.is_occupied() {
+            // SAFETY: The value of an occupied node is initialized.
+            unsafe { self.value.assume_init_drop() }
+        }
+    }
+}
+
+impl<T: Clone, I: IndexType> Clone for Node<T, I> {
+    /// Clones the node along with its links, and its value if it's occupied.
+    /// 
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            value : self.value().map_or(MaybeUninit::uninit(), 
+                                        |v| MaybeUninit::new(v.clone())),
+            next  : self.next,
+            prev  : self.prev,
+
+            #[cfg(feature = "generational-handles")]
+            gen   : self.gen,
+        }
+    }
+}
+
+/// A link held by a node, which tests can compare with the handle of the node
+/// it's expected to refer to.
+/// 
+#[cfg(test)]
+#[derive(Debug)]
+pub(crate) struct Link<I>(I);
+
+#[cfg(test)]
+impl<I: IndexType> PartialEq<HNode<I>> for Link<I> {
+    fn eq(&self, other: &HNode<I>) -> bool {
+        self.0 == other.0
     }
 }
 
@@ -95,33 +500,86 @@ impl<T> Node<T> {
 /// within a vector. This allows for O(1) insertion and removal of elements
 /// from the list, and O(1) access to elements by handle.
 /// 
-pub struct LinkedVector<T> {
-    vec   : Vec<Node<T>>,
-    head  : HNode,
-    recyc : HNode,
-    len   : usize,
+/// The nodes are held in a `Vec` by default. Other kinds of storage can be 
+/// chosen through the `S` parameter, such as [ArrayStorage], which is used by
+/// [ArrayLinkedVector] to hold a fixed number of nodes without allocating, or
+/// [PagedStorage], which is used by [PagedLinkedVector] to grow without moving
+/// the nodes it already holds. Any type that implements [NodeStorage] can be
+/// used.
+/// 
+pub struct LinkedVector<T, I: IndexType = usize, S = Vec<Node<T, I>>> {
+    pub(crate) vec   : S,
+    pub(crate) head  : HNode<I>,
+    pub(crate) recyc : HNode<I>,
+    pub(crate) len   : usize,
+    policy           : RecyclePolicy,
+    compaction       : Option<Box<CompactionPolicy<I>>>,
+
+    // The number of nodes at the front of the recycle list that haven't been
+    // put in order of index yet. Policies other than `Lifo` merge them into
+    // the rest of the list before a node is taken from it.
+    unsorted         : usize,
+
+    // The elements are owned through the storage, which is generic.
+    _marker          : PhantomData<T>,
+
+    // The lowest generation a node can be given. Nodes added to the end of
+    // the internal vector start at it. It's raised above the generations of
+    // the nodes the internal vector lets go of, so handles to them can't match
+    // the nodes that later take their place, until generations wrap around.
+    #[cfg(feature = "generational-handles")]
+    pub(crate) floor : I,
 
     // This field is used to detect foreign handles. If a handle's
     // 3rd field doesn't match this, it's foreign.
-    #[cfg(debug_assertions)]
-    uuid  : Uuid,
+    #[cfg(feature = "checked-handles")]
+    pub(crate) id    : usize,
+
+    // The ID of the vector this one was cloned from by `clone_with_layout()`,
+    // and the floor the clone started with. Nodes whose generations are below
+    // it haven't changed since, so their handles translate between the two.
+    #[cfg(feature = "checked-handles")]
+    origin           : Option<(usize, I)>,
 }
 
+/// A `LinkedVector` that holds up to `N` nodes inline in an array, without 
+/// any heap allocation. It has the same API as a `LinkedVector`, but operations
+/// that add an element panic if all the nodes are taken, unless they're the 
+/// fallible kind, like [try_push_back()](LinkedVector::try_push_back). Nodes
+/// freed by removing elements are reused. Create one with 
+/// [new_array()](LinkedVector::new_array).
+/// 
+pub type ArrayLinkedVector<T, const N: usize, I = usize> 
+    = LinkedVector<T, I, ArrayStorage<Node<T, I>, N>>;
+
+/// A `LinkedVector` that holds its nodes in pages of `PAGE` nodes each. Pages
+/// are added as the vector grows, and the nodes in the existing ones are never
+/// moved, so adding elements never copies the others, and the addresses of 
+/// the elements stay the same until they're removed or relocated.
+/// Create one with [new_paged()](LinkedVector::new_paged). See [PagedStorage].
+/// 
+pub type PagedLinkedVector<T, I = usize, const PAGE: usize = 1024> 
+    = LinkedVector<T, I, PagedStorage<Node<T, I>, PAGE>>;
+
+/// A `LinkedVector` whose nodes are allocated from the allocator, `A`, rather
+/// than the global allocator. Dropping the vector, or freeing the memory of an
+/// arena allocator it uses, releases all its nodes at once. Create one with 
+/// [new_in()](LinkedVector::new_in) or 
+/// [with_capacity_in()](LinkedVector::with_capacity_in). Requires the 
+/// `"allocator-api2"` feature. The fallible methods report any failure of the
+/// allocator as a capacity overflow; see `LinkedVectorError::ReserveFailed`.
+/// 
+#[cfg(feature = "allocator-api2")]
+pub type AllocLinkedVector<T, A, I = usize> 
+    = LinkedVector<T, I, allocator_api2::vec::Vec<Node<T, I>, A>>;
+
 impl<T> LinkedVector<T> {
     /// Creates a new, empty `LinkedVector`.
     /// 
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        Self { 
-            vec   : Vec::new(), 
-            recyc : BAD_HANDLE, 
-            head  : BAD_HANDLE, 
-            len   : 0, 
-
-            #[cfg(debug_assertions)]
-            uuid  : uuid::Uuid::new_v4() 
-        }
+        Self::new_indexed()
     }
 
     /// Creates a new, empty `LinkedVector` with the specified capacity.
@@ -129,19 +587,139 @@ impl<T> LinkedVector<T> {
     #[inline]
     #[must_use]
     pub fn with_capacity(size: usize) -> Self {
-        Self { 
-            vec   : Vec::with_capacity(size), 
-            recyc : BAD_HANDLE, 
-            head  : BAD_HANDLE, 
-            len   : 0, 
+        Self::with_capacity_indexed(size)
+    }
+}
 
-            #[cfg(debug_assertions)]
-            uuid  : uuid::Uuid::new_v4() 
-        }
+impl<T, I: IndexType> LinkedVector<T, I> {
+    /// Creates a new, empty `LinkedVector` that uses `I` as the index type of
+    /// its nodes and handles. A narrower index type than the default `usize`
+    /// reduces the size of each node. See [IndexType].
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::<char, u16>::new_indexed();
+    /// let h1 = lv.push_back('a');
+    /// 
+    /// assert_eq!(lv[h1], 'a');
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new_indexed() -> Self {
+        Self::with_capacity_(0)
+    }
+
+    /// Creates a new, empty `LinkedVector` with the specified capacity that 
+    /// uses `I` as the index type of its nodes and handles.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn with_capacity_indexed(size: usize) -> Self {
+        Self::with_capacity_(size)
+    }
+}
+
+impl<T, I: IndexType, const N: usize> ArrayLinkedVector<T, N, I> {
+    /// Creates a new, empty `ArrayLinkedVector`, which holds up to `N` nodes 
+    /// inline without allocating. Pushing or inserting an element when all
+    /// the nodes are taken panics, while 
+    /// [try_push_back()](LinkedVector::try_push_back) and 
+    /// [try_push_front()](LinkedVector::try_push_front) return an error.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = ArrayLinkedVector::<i32, 2>::new_array();
+    /// let h1 = lv.push_back(1);
+    /// let h2 = lv.push_back(2);
+    /// 
+    /// assert!(lv.try_push_back(3).is_err());
+    /// 
+    /// lv.remove(h1);
+    /// 
+    /// assert!(lv.try_push_back(3).is_ok());
+    /// assert_eq!(lv.to_vec(), vec![2, 3]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new_array() -> Self {
+        Self::with_capacity_(N)
+    }
+}
+
+impl<T, I: IndexType, const PAGE: usize> PagedLinkedVector<T, I, PAGE> {
+    /// Creates a new, empty `PagedLinkedVector`. No page is allocated until 
+    /// the first element is added.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = PagedLinkedVector::<i32>::new_paged();
+    /// let h1 = lv.push_back(1);
+    /// let p1 = &lv[h1] as *const i32;
+    /// 
+    /// lv.extend(2..10_000);
+    /// 
+    /// assert_eq!(&lv[h1] as *const i32, p1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new_paged() -> Self {
+        Self::with_capacity_(0)
+    }
+}
+
+#[cfg(feature = "allocator-api2")]
+impl<T, I, A> AllocLinkedVector<T, A, I> 
+where
+    I: IndexType,
+    A: Allocator + Clone,
+{
+    /// Creates a new, empty `AllocLinkedVector` whose nodes are allocated from
+    /// `alloc`. Vectors made from it, by methods like 
+    /// [split_off()](LinkedVector::split_off), or by cloning it, allocate from
+    /// a clone of `alloc`.
+    /// ```
+    /// use allocator_api2::alloc::Global;
+    /// use linked_vector::*;
+    /// let mut lv = AllocLinkedVector::<i32, _>::new_in(Global);
+    /// let h1 = lv.push_back(1);
+    /// 
+    /// assert_eq!(lv[h1], 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new_in(alloc: A) -> Self {
+        Self::from_storage_(allocator_api2::vec::Vec::new_in(alloc))
+    }
+
+    /// Creates a new, empty `AllocLinkedVector` with the specified capacity
+    /// whose nodes are allocated from `alloc`.
+    /// ```
+    /// use allocator_api2::alloc::Global;
+    /// use linked_vector::*;
+    /// let lv = AllocLinkedVector::<i32, _>::with_capacity_in(10, Global);
+    /// 
+    /// assert!(lv.capacity() >= 10);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_capacity_in(size: usize, alloc: A) -> Self {
+        Self::from_storage_(
+            allocator_api2::vec::Vec::with_capacity_in(size, alloc))
+    }
+
+    /// Returns a reference to the allocator the nodes are allocated from.
+    /// 
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.vec.allocator()
     }
+}
+
+impl<T, I: IndexType, S: NodeStorage<Node<T, I>>> LinkedVector<T, I, S> {
 
     /// Moves all elements from `other` into `self`, leaving `other` empty.
-    /// This operation completes in O(n) time where n is the length of `other`.
+    /// The nodes of `other` are moved in bulk to the end of the internal 
+    /// vector of `self`, which is grown at most once. Panics if the nodes of
+    /// both don't fit in the index type or the storage of `self`, in which 
+    /// case both vectors are left as they were. This operation completes in 
+    /// O(n) time where n is the number of nodes in `other`'s internal vector.
     /// ```
     /// use linked_vector::*;
     /// let mut lv1 = LinkedVector::new();
@@ -154,10 +732,33 @@ impl<T> LinkedVector<T> {
     /// ```
     #[inline]
     pub fn append(&mut self, other: &mut Self) {
-        while let Some(value) = other.pop_front() {
-            self.push_back(value);
+        self.append_(other);
+    }
+
+    /// Moves all elements from `other` into `self`, leaving `other` empty, and
+    /// returns a remap that translates the handles of `other` into handles for
+    /// the same elements in `self`. See [append()](LinkedVector::append). This
+    /// operation completes in O(n) time where n is the number of nodes in
+    /// `other`'s internal vector.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv1 = LinkedVector::from([1, 2, 3]);
+    /// let mut lv2 = LinkedVector::from([4, 5, 6]);
+    /// let h5 = lv2.handle(1).unwrap();
+    /// 
+    /// let remap = lv1.append_with_remap(&mut lv2);
+    /// 
+    /// assert_eq!(lv1[remap.get(h5).unwrap()], 5);
+    /// ```
+    pub fn append_with_remap(&mut self, other: &mut Self) -> HNodeRemap<I> {
+        let olds   = other.handles().collect::<Vec<_>>();
+        let mut map = vec![(HNode::BAD, HNode::BAD); other.vec.len()];
+        let offset = self.append_(other);
+
+        for hold in olds {
+            map[hold.0.index()] = (hold, self.hnode_(hold.0.index() + offset));
         }
-        other.clear();
+        HNodeRemap { map }
     }
 
     /// Gives a reference to the back element, or `None` if the list is  empty.
@@ -172,7 +773,7 @@ impl<T> LinkedVector<T> {
         if self.is_empty() {
             None
         } else {
-            self.back_().unwrap().value.as_ref()
+            self.back_().unwrap().value()
         }
     }
 
@@ -191,7 +792,7 @@ impl<T> LinkedVector<T> {
         if self.is_empty() {
             None
         } else {
-            self.get_mut_(self.get_(self.head).prev).value.as_mut()
+            self.get_mut_(self.prev_(self.head)).value_mut()
         }
     }
 
@@ -203,20 +804,137 @@ impl<T> LinkedVector<T> {
         self.vec.capacity()
     }
 
-    /// Removes all elements from the list.
+    /// Removes all elements from the list. Handles to the removed elements 
+    /// remain expired after new elements are added.
     /// 
     #[inline]
     pub fn clear(&mut self) {
+        #[cfg(feature = "generational-handles")]
+        self.raise_floor_();
+
         self.vec.clear();
         self.len = 0;
-        self.head = BAD_HANDLE;
-        self.recyc = BAD_HANDLE;
+        self.head = HNode::BAD;
+        self.recyc = HNode::BAD;
+        self.unsorted = 0;
     }
     
-    /// Consumes the LinkedVector and produces a new one that has all its nodes 
-    /// placed contiguously in sequential order at the front of the internal 
-    /// vector. Where performance is critical and the cost of a compacting 
-    /// operation is infrequent and acceptible, compacting the vector *may* give
+    /// Clones the vector along with the layout of its internal vector. Every 
+    /// node is copied to the same position, including the vacant ones and the
+    /// recycle list, so each element of the clone is at the same node as in
+    /// `self`. Like [clone()](Clone::clone), the clone gets its own ID, so the
+    /// handles of each vector are foreign to the other. Use 
+    /// [translate_handle()](LinkedVector::translate_handle) to turn a handle
+    /// from one into a handle to the same element of the other. This 
+    /// operation completes in O(n) time where n is the number of nodes in the
+    /// internal vector.
+    /// 
+    /// Without the `"checked-handles"` feature, handles aren't checked against
+    /// the vector they came from. A handle from one vector can then be used 
+    /// with the other, but once either has added or removed elements, it may 
+    /// refer to an unrelated element there.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv1 = LinkedVector::from([1, 2, 3]);
+    /// let h2 = lv1.handle(1).unwrap();
+    /// let h3 = lv1.handle(2).unwrap();
+    /// 
+    /// lv1.remove(h2);
+    /// 
+    /// let mut lv2 = lv1.clone_with_layout();
+    /// let h3_2    = lv2.handle(1).unwrap();
+    /// 
+    /// lv2[h3_2] = 42;
+    /// 
+    /// assert_eq!(lv1[h3], 3);
+    /// assert_eq!(lv2.to_vec(), vec![1, 42]);
+    /// ```
+    #[must_use]
+    pub fn clone_with_layout(&self) -> Self 
+    where
+        T: Clone,
+    {
+        let mut lv = self.new_like_(self.vec.len());
+        for i in 0..self.vec.len() {
+            lv.vec.push(self.vec[i].clone());
+        }
+        lv.head     = lv.hlink_(self.head.0);
+        lv.recyc    = lv.hlink_(self.recyc.0);
+        lv.len      = self.len;
+        lv.unsorted = self.unsorted;
+
+        // The clone gives its nodes generations from its floor on, and the 
+        // vacant nodes are raised to it, so the handles it issues can't match
+        // the nodes `self` issues handles for.
+        #[cfg(feature = "generational-handles")]
+        {
+            lv.floor = self.floor;
+            lv.raise_floor_();
+            for i in 0..lv.vec.len() {
+                if !lv.vec[i].is_occupied() {
+                    lv.vec[i].gen = lv.floor;
+                }
+            }
+            lv.recyc = lv.hlink_(lv.recyc.0);
+        }
+        #[cfg(feature = "checked-handles")]
+        { lv.origin = Some((self.id, lv.floor)); }
+
+        lv
+    }
+
+    /// Translates a handle from `from` into a handle to the same element of
+    /// `self`, where one of the two vectors was cloned from the other by 
+    /// [clone_with_layout()](LinkedVector::clone_with_layout). `None` is 
+    /// returned if the handle isn't valid for `from`, if the vectors aren't
+    /// related that way, or if the element's node has been vacated or 
+    /// relocated in either vector since the clone was made. This operation 
+    /// completes in O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv1 = LinkedVector::from([1, 2, 3]);
+    /// let h1 = lv1.handle(0).unwrap();
+    /// let h2 = lv1.handle(1).unwrap();
+    /// 
+    /// let mut lv2 = lv1.clone_with_layout();
+    /// 
+    /// lv1.remove(h2);
+    /// let h4 = lv1.push_back(4);
+    /// 
+    /// let h1_2 = lv2.translate_handle(&lv1, h1).unwrap();
+    /// 
+    /// assert_eq!(lv2[h1_2], 1);
+    /// assert_eq!(lv1.translate_handle(&lv2, h1_2), Some(h1));
+    /// assert_eq!(lv2.translate_handle(&lv1, h4), None);
+    /// ```
+    #[cfg(feature = "checked-handles")]
+    pub fn translate_handle(&self, from: &Self, node: impl NodeHandle<T, I>) 
+        -> Option<HNode<I>> 
+    {
+        let node = node.hnode();
+        from.validate_handle(node).ok()?;
+
+        let floor = match (self.origin, from.origin) {
+            (Some((id, floor)), _) if id == from.id => floor,
+            (_, Some((id, floor))) if id == self.id => floor,
+            _ => return None,
+        };
+        // The clone hasn't given any node a generation below the floor since
+        // it was made, and generations only rise, so a node that has the same
+        // generation below it in both vectors still holds the element it held
+        // when the clone was made.
+        if node.1 >= floor {
+            return None;
+        }
+        let hnode = HNode(node.0, node.1, self.id);
+        self.validate_handle(hnode).ok()?;
+        Some(hnode)
+    }
+
+    /// Consumes the LinkedVector and produces a new one that has all its nodes 
+    /// placed contiguously in sequential order at the front of the internal 
+    /// vector. Where performance is critical and the cost of a compacting 
+    /// operation is infrequent and acceptible, compacting the vector *may* give
     /// a gain in performance for certain use cases. All handles from the old 
     /// vector will not be native to the new compacted vector. `compact()` 
     /// completes in O(n) time.
@@ -224,7 +942,102 @@ impl<T> LinkedVector<T> {
     #[inline]
     #[must_use]
     pub fn compact(self) -> Self {
-        self.into_iter().collect()
+        let mut lv = self.new_like_(self.len);
+        lv.extend(self);
+        lv
+    }
+
+    /// Relocates the nodes of the vector so they're placed contiguously in 
+    /// sequential order at the front of the internal vector, and releases the
+    /// nodes in the recycle list. Unlike [compact()](LinkedVector::compact),
+    /// the vector is compacted in place, and a remap is returned that 
+    /// translates the handles that were valid before the operation into 
+    /// handles for the same elements afterwards. Nodes that were already in
+    /// position keep their handles. Handles that aren't translated through
+    /// the remap shouldn't be used after compacting. This operation completes
+    /// in O(n) time and requires auxiliary memory for the remap.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5]);
+    /// let h2 = lv.handle(1).unwrap();
+    /// let h5 = lv.back_node().unwrap();
+    /// 
+    /// lv.remove(h2);
+    /// 
+    /// let remap = lv.compact_in_place();
+    /// let h5    = remap.get(h5).unwrap();
+    /// 
+    /// assert_eq!(lv[h5], 5);
+    /// assert_eq!(remap.get(h2), None);
+    /// assert_eq!(lv.to_vec(), vec![1, 3, 4, 5]);
+    /// ```
+    pub fn compact_in_place(&mut self) -> HNodeRemap<I> {
+        let order = self.handles().collect::<Vec<_>>();
+        let slots = self.vec.len();
+
+        // `slot_of` tracks where each node currently is by its original index,
+        // and `orig_at` tracks which node is in each slot.
+        let mut slot_of = (0..slots).collect::<Vec<_>>();
+        let mut orig_at = (0..slots).collect::<Vec<_>>();
+
+        #[cfg(feature = "generational-handles")]
+        let gens = (0..slots).map(|i| self.vec[i].gen).collect::<Vec<_>>();
+
+        for (i, hnode) in order.iter().enumerate() {
+            let j = slot_of[hnode.0.index()];
+            if i != j {
+                self.vec.swap(i, j);
+                let displaced = orig_at[i];
+                orig_at.swap(i, j);
+                slot_of[hnode.0.index()] = i;
+                slot_of[displaced] = j;
+            }
+        }
+        // The nodes past the end are released, so the slots they held will be
+        // given generations that their handles don't match if they're reused.
+        #[cfg(feature = "generational-handles")]
+        self.raise_floor_();
+
+        self.vec.truncate(self.len);
+        self.recyc = HNode::BAD;
+        self.unsorted = 0;
+
+        // Slots that received a different node get a new generation so the
+        // old handles to them are detected as expired.
+        #[cfg(feature = "generational-handles")]
+        for (i, hnode) in order.iter().enumerate() {
+            if hnode.0.index() != i {
+                self.vec[i].gen = gens[i].next_gen().max(self.floor);
+            }
+        }
+        let mut map = vec![(HNode::BAD, HNode::BAD); slots];
+
+        for (i, hold) in order.into_iter().enumerate() {
+            let hnew = self.hnode_(i);
+            map[hold.0.index()] = (hold, hnew);
+            if i == 0 {
+                self.head = hnew;
+            } else {
+                let hprev = self.hnode_(i - 1);
+                self.vec[i].prev = hprev.0;
+                self.vec[i - 1].next = hnew.0;
+            }
+        }
+        if let Some(last) = self.len.checked_sub(1) {
+            self.vec[0].prev = self.hnode_(last).0;
+            self.vec[last].next = I::NONE;
+        } else {
+            self.head = HNode::BAD;
+        }
+        HNodeRemap { map }
+    }
+
+    /// Returns the policy that compacts the vector automatically, if one is
+    /// set. See [CompactionPolicy].
+    /// 
+    #[inline]
+    pub fn compaction_policy(&self) -> Option<&CompactionPolicy<I>> {
+        self.compaction.as_deref()
     }
 
     /// Returns `true` if the list contains an element with the given value.
@@ -239,7 +1052,10 @@ impl<T> LinkedVector<T> {
     }
 
     /// Creates a cursor that can be used to traverse the list starting at the
-    /// given node. This operation completes in O(1) time.
+    /// given node. With the `"generational-handles"` feature, panics if `node` 
+    /// is invalid, foreign, or expired; see 
+    /// [try_cursor()](LinkedVector::try_cursor) for a version that returns an
+    /// error instead. This operation completes in O(1) time.
     /// ```
     /// use linked_vector::*;
     /// let lv = LinkedVector::from([1, 2, 3, 4, 5, 6, 7, 8, 9]);
@@ -251,13 +1067,16 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(*cursor, 6);
     /// ```
     #[inline]
-    pub fn cursor(&self, node: HNode) -> Cursor<T> {
+    pub fn cursor(&self, node: impl NodeHandle<T, I>) -> Cursor<T, I, S> {
+        let node = node.hnode();
         Cursor::new(self, node)
     }
 
     /// Creates a cursor that holds a mutable reference to the LinkedVector that
-    /// can be used to traverse the list starting at the given node. If the 
-    /// vector is empty, `None` is returned. This operation completes in O(1)
+    /// can be used to traverse the list starting at the given node. With the
+    /// `"generational-handles"` feature, panics if `node` is invalid, foreign,
+    /// or expired; see [try_cursor_mut()](LinkedVector::try_cursor_mut) for a
+    /// version that returns an error instead. This operation completes in O(1)
     /// time.
     /// ```
     /// use linked_vector::*;
@@ -273,7 +1092,10 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(lv.to_vec(), vec![1, 2, 3, 42, 5, 6]);
     /// ```
     #[inline]
-    pub fn cursor_mut(&mut self, node: HNode) -> CursorMut<T> {
+    pub fn cursor_mut(&mut self, node: impl NodeHandle<T, I>) 
+        -> CursorMut<T, I, S> 
+    {
+        let node = node.hnode();
         CursorMut::new(self, node)
     }
 
@@ -282,7 +1104,7 @@ impl<T> LinkedVector<T> {
     /// completes in O(1) time.
     /// 
     #[inline]
-    pub fn cursor_back(&self) -> Option<Cursor<T>> {
+    pub fn cursor_back(&self) -> Option<Cursor<T, I, S>> {
         if self.is_empty() {
             None
         } else {
@@ -294,7 +1116,7 @@ impl<T> LinkedVector<T> {
     /// empty. This operation completes in O(1) time.
     /// 
     #[inline]
-    pub fn cursor_back_mut(&mut self) -> Option<CursorMut<T>> {
+    pub fn cursor_back_mut(&mut self) -> Option<CursorMut<T, I, S>> {
         if self.is_empty() {
             None
         } else {
@@ -306,7 +1128,7 @@ impl<T> LinkedVector<T> {
     /// if the list is empty. This operation completes in O(1) time.
     /// 
     #[inline]
-    pub fn cursor_front(&self) -> Option<Cursor<T>> {
+    pub fn cursor_front(&self) -> Option<Cursor<T, I, S>> {
         if self.is_empty() {
             None
         } else {
@@ -318,7 +1140,7 @@ impl<T> LinkedVector<T> {
     /// the list is empty. This operation completes in O(1) time.
     /// 
     #[inline]
-    pub fn cursor_front_mut(&mut self) -> Option<CursorMut<T>> {
+    pub fn cursor_front_mut(&mut self) -> Option<CursorMut<T, I, S>> {
         if self.is_empty() {
             None
         } else {
@@ -326,6 +1148,106 @@ impl<T> LinkedVector<T> {
         }
     }
 
+    /// Removes the elements within the given range of handles and returns them
+    /// through an iterator. The range can be any of the standard range types
+    /// bounded by handles, such as `h1..h2`, `h1..=h2`, `h1..`, or `..` to 
+    /// drain the whole list. The elements are unlinked from the list as the
+    /// iterator advances. If the iterator is dropped before it's exhausted, the
+    /// rest of the elements in the range are removed. Panics if the end of the
+    /// range precedes its start. Creating the iterator completes in O(k) time,
+    /// where k is the number of elements in the range. Removing each element
+    /// takes O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5, 6]);
+    /// let h2 = lv.handle(1).unwrap();
+    /// let h5 = lv.handle(4).unwrap();
+    /// 
+    /// assert_eq!(lv.drain(h2..h5).collect::<Vec<_>>(), vec![2, 3, 4]);
+    /// assert_eq!(lv.to_vec(), vec![1, 5, 6]);
+    /// 
+    /// assert_eq!(lv.drain(..).rev().collect::<Vec<_>>(), vec![6, 5, 1]);
+    /// assert!(lv.is_empty());
+    /// ```
+    #[inline]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, I, S> 
+    where
+        R: HandleRange<T, I>,
+    {
+        let (hnode, hrev, len) = self.range_(range);
+        Drain { lv: self, hnode, hrev, len }
+    }
+
+    /// Returns an iterator that removes and yields the elements for which the
+    /// predicate returns `true`. The elements are unlinked from the list as 
+    /// the iterator advances. If the iterator is dropped before it's 
+    /// exhausted, the elements it hasn't visited remain in the list. Handles
+    /// to the elements that aren't extracted remain valid. Visiting or 
+    /// removing each element takes O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5, 6]);
+    /// 
+    /// let evens = lv.extract_if(|v| *v % 2 == 0).collect::<Vec<_>>();
+    /// 
+    /// assert_eq!(evens, vec![2, 4, 6]);
+    /// assert_eq!(lv.to_vec(), vec![1, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F, I, S> 
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        ExtractIf {
+            hnode : self.front_node().unwrap_or(HNode::BAD),
+            lv    : self,
+            pred,
+        }
+    }
+
+    /// Measures how fragmented the vector is, as the sum of two ratios, each
+    /// from 0 to 1: the share of the nodes in the internal vector that are 
+    /// vacant, and the share of the links between consecutive elements that
+    /// don't lead to the next node in the internal vector. A compact vector,
+    /// such as one returned by [compact()](LinkedVector::compact), measures 0.
+    /// This operation completes in O(n) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4]);
+    /// let h2 = lv.handle(1).unwrap();
+    /// 
+    /// assert_eq!(lv.fragmentation(), 0.0);
+    /// 
+    /// lv.remove(h2);
+    /// 
+    /// // 1 of 4 nodes is vacant, and 1 of the 2 links skips over it.
+    /// assert_eq!(lv.fragmentation(), 0.25 + 0.5);
+    /// ```
+    pub fn fragmentation(&self) -> f64 {
+        let nodes = self.vec.len();
+        if nodes == 0 {
+            return 0.0;
+        }
+        let vacant = self.vacant_count() as f64 / nodes as f64;
+        let links  = self.len.saturating_sub(1);
+        if links == 0 {
+            return vacant;
+        }
+        let mut out_of_order = 0;
+        let mut inode = self.head.0;
+        loop {
+            let inext = self.vec[inode.index()].next;
+            if inext == I::NONE {
+                break;
+            }
+            if inext.index() != inode.index() + 1 {
+                out_of_order += 1;
+            }
+            inode = inext;
+        }
+        vacant + out_of_order as f64 / links as f64
+    }
+
     /// Gives a reference to the element at the front of the vector, or `None` 
     /// if the list is empty. This operation completes in O(1) time.
     /// 
@@ -334,7 +1256,7 @@ impl<T> LinkedVector<T> {
         if self.is_empty() {
             None
         } else {
-            self.front_().unwrap().value.as_ref()
+            self.front_().unwrap().value()
         }
     }
 
@@ -346,7 +1268,7 @@ impl<T> LinkedVector<T> {
         if self.is_empty() {
             None
         } else {
-            self.get_mut_(self.head).value.as_mut()
+            self.get_mut_(self.head).value_mut()
         }
     }
 
@@ -361,7 +1283,7 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(lv.front(), Some(&42));
     /// ```
     #[inline]
-    pub fn front_node(&self) -> Option<HNode> {
+    pub fn front_node(&self) -> Option<HNode<I>> {
         if self.len == 0 {
             None
         } else {
@@ -373,8 +1295,8 @@ impl<T> LinkedVector<T> {
     /// empty. This operation completes in O(1) time. 
     /// 
     #[inline]
-    pub fn back_node(&self) -> Option<HNode> {
-        self.front_().map(|node| node.prev)
+    pub fn back_node(&self) -> Option<HNode<I>> {
+        self.front_().map(|node| self.hlink_(node.prev))
     }
 
     /// Provides a reference to the element indicated by the given handle. This
@@ -391,12 +1313,15 @@ impl<T> LinkedVector<T> {
     /// ```
     #[inline]
     #[cfg(feature = "optionless-accessors")]
-    pub fn get(&self, node: HNode) -> &T {
-        self.get_(node).value.as_ref().unwrap()
+    pub fn get(&self, node: impl NodeHandle<T, I>) -> &T {
+        let node = node.hnode();
+        self.get_(node).value().unwrap()
     }
 
     /// Provides a reference to the element indicated by the given handle, or
-    /// `None` if the handle is invalid. With the "optionless-accesors" feature,
+    /// `None` if the handle is invalid, expired, or foreign to the vector (see
+    /// [try_get()](LinkedVector::try_get) for the reason). With the 
+    /// "optionless-accesors" feature,
     /// this method returns its reference directly - no `Option`, 
     /// see [usage notes](./index.html#feature-optionless-accessors). This 
     /// operation completes in O(1) time.
@@ -409,8 +1334,10 @@ impl<T> LinkedVector<T> {
     /// ```    
     #[inline]
     #[cfg(not(feature = "optionless-accessors"))]
-    pub fn get(&self, node: HNode) -> Option<&T> {
-        self.get_(node).value.as_ref()
+    pub fn get(&self, node: impl NodeHandle<T, I>) -> Option<&T> {
+        let node = node.hnode();
+        self.validate_handle(node).ok()?;
+        self.get_(node).value()
     }
 
     /// Provides a mutable reference to the element indicated by the given
@@ -429,12 +1356,14 @@ impl<T> LinkedVector<T> {
     /// ```
     #[inline]
     #[cfg(feature = "optionless-accessors")]
-    pub fn get_mut(&mut self, node: HNode) -> &mut T {
-        self.get_mut_(node).value.as_mut().unwrap()
+    pub fn get_mut(&mut self, node: impl NodeHandle<T, I>) -> &mut T {
+        let node = node.hnode();
+        self.get_mut_(node).value_mut().unwrap()
     }
 
     /// Provides a mutable reference to the element indicated by the given
-    /// handle, or `None` if the handle is invalid. With the 
+    /// handle, or `None` if the handle is invalid, expired, or foreign to the
+    /// vector. With the 
     /// "optionless-accessors" feature enabled, this method returns its 
     /// reference directly - no `Option`, see 
     /// [usage notes](./index.html#feature-optionless-accessors). 
@@ -450,8 +1379,10 @@ impl<T> LinkedVector<T> {
     /// ```
     #[inline]
     #[cfg(not(feature = "optionless-accessors"))]
-    pub fn get_mut(&mut self, node: HNode) -> Option<&mut T> {
-        self.get_mut_(node).value.as_mut()
+    pub fn get_mut(&mut self, node: impl NodeHandle<T, I>) -> Option<&mut T> {
+        let node = node.hnode();
+        self.validate_handle(node).ok()?;
+        self.get_mut_(node).value_mut()
     }
 
     /// Returns the handle to the node at the given index, or `None` if the
@@ -470,7 +1401,7 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(lv.handle(2), Some(h1));
     /// ```
     #[inline]
-    pub fn handle(&self, index: usize) -> Option<HNode> {
+    pub fn handle(&self, index: usize) -> Option<HNode<I>> {
         if index <= self.len / 2 {
             self.handles().nth(index)
         } else if index >= self.len {
@@ -480,6 +1411,48 @@ impl<T> LinkedVector<T> {
         }
     }
 
+    /// Converts a value produced by [HNode::into_raw()] back into a handle for
+    /// this vector. The value is validated against the vector, and an error is
+    /// returned if it doesn't refer to an element of it. Since only the low 32
+    /// bits of the generation are kept, a handle to a node that has since been
+    /// recycled a multiple of 2^32 times isn't detected as expired. A value 
+    /// from another vector isn't detected as foreign either; it's accepted if
+    /// this vector has an element at the same node with a matching generation.
+    /// Either way, the handle returned is native to this vector and refers to
+    /// the element it holds at that node. This operation completes in O(1) 
+    /// time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3]);
+    /// let raw = lv.handle(1).unwrap().into_raw();
+    /// 
+    /// assert_eq!(lv[lv.handle_from_raw(raw).unwrap()], 2);
+    /// 
+    /// lv.remove(lv.handle(1).unwrap());
+    /// 
+    /// assert_eq!(lv.handle_from_raw(raw), 
+    ///            Err(LinkedVectorError::ExpiredHandle));
+    /// ```
+    pub fn handle_from_raw(&self, raw: u64) 
+        -> Result<HNode<I>, LinkedVectorError> 
+    {
+        if self.is_empty() {
+            return Err(LinkedVectorError::EmptyList);
+        }
+        let index = (raw as u32) as usize;
+
+        if raw == u64::MAX || index >= self.vec.len() {
+            return Err(LinkedVectorError::InvalidHandle);
+        }
+        #[cfg(feature = "generational-handles")]
+        if self.vec[index].gen.index() as u32 != (raw >> 32) as u32 {
+            return Err(LinkedVectorError::ExpiredHandle);
+        }
+        let hnode = self.hnode_(index);
+        self.validate_handle(hnode)?;
+        Ok(hnode)
+    }
+
     /// Returns an iterator over the handles of the vector. The handles will 
     /// reflect the order of the linked list. This operation completes in O(1) 
     /// time.
@@ -496,13 +1469,46 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(iter.collect::<Vec<_>>(), vec![h1, h2, h3]);
     /// ```
     #[inline]
-    pub fn handles(&self) -> Handles<T> {
+    pub fn handles(&self) -> Handles<T, I, S> {
         Handles::new(self)
     }
 
+    /// Returns an iterator over the handles of the vector starting at the given
+    /// handle and continuing to the end of the list. See 
+    /// [iter_range()](LinkedVector::iter_range) for details.
+    /// 
+    #[inline]
+    pub fn handles_from(&self, node: impl NodeHandle<T, I>) 
+        -> Handles<'_, T, I, S> 
+    {
+        let node = node.hnode();
+        self.handles_range(node..)
+    }
+
+    /// Returns an iterator over the handles within the given range of handles.
+    /// See [iter_range()](LinkedVector::iter_range) for details.
+    /// ```
+    /// use linked_vector::*;
+    /// let lv = LinkedVector::from([1, 2, 3, 4]);
+    /// let hs = lv.handles().collect::<Vec<_>>();
+    /// 
+    /// assert_eq!(lv.handles_range(hs[1]..=hs[2]).collect::<Vec<_>>(), 
+    ///            vec![hs[1], hs[2]]);
+    /// ```
+    #[inline]
+    pub fn handles_range<R>(&self, range: R) -> Handles<'_, T, I, S> 
+    where
+        R: HandleRange<T, I>,
+    {
+        let (hnode, hrev, len) = self.range_(range);
+        Handles { lv: self, hnode, hrev, len }
+    }
+
     /// Inserts a new element at the position indicated by the handle, `node`.
-    /// Returns a handle to the newly inserted element. This operation completes
-    /// in O(1) time.
+    /// Returns a handle to the newly inserted element. Panics if `node` is 
+    /// invalid, foreign, or expired, in which case the vector is left as it 
+    /// was; see [try_insert()](LinkedVector::try_insert) for a version that
+    /// returns an error instead. This operation completes in O(1) time.
     /// ```
     /// use linked_vector::*;
     /// let mut lv = LinkedVector::new();
@@ -514,13 +1520,20 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(lv[h1], 42);
     /// ```
     #[inline]
-    pub fn insert(&mut self, node: HNode, value: T) -> HNode {
+    pub fn insert(&mut self, node: impl NodeHandle<T, I>, value: T) 
+        -> HNode<I> 
+    {
+        let node = node.hnode();
+        self.assert_handle_(node);
         self.insert_(Some(node), value)
     }
 
     /// Inserts a new element after the one indicated by the handle, `node`.
-    /// Returns a handle to the newly inserted element. This operation completes
-    /// in O(1) time.
+    /// Returns a handle to the newly inserted element. Panics if `node` is 
+    /// invalid, foreign, or expired, in which case the vector is left as it 
+    /// was; see [try_insert_after()](LinkedVector::try_insert_after) for a 
+    /// version that returns an error instead. This operation completes in 
+    /// O(1) time.
     /// ```
     /// use linked_vector::*;
     /// let mut lv = LinkedVector::new();
@@ -532,14 +1545,35 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(lv[h2], 43);
     /// ```
     #[inline]
-    pub fn insert_after(&mut self, node: HNode, value: T) -> HNode {
-        if let Some(next) = self.next_node(node) {
+    pub fn insert_after(&mut self, node: impl NodeHandle<T, I>, value: T) 
+        -> HNode<I> 
+    {
+        let node = node.hnode();
+        self.assert_handle_(node);
+        let next = self.next_(node);
+        if next != HNode::BAD {
             self.insert_(Some(next), value)
         } else {
             self.insert_(None, value)
         }
     }
 
+    /// Consumes the vector and returns an iterator that yields each element 
+    /// along with the handle it had in the vector.
+    /// ```
+    /// use linked_vector::*;
+    /// let lv = LinkedVector::from([1, 2, 3]);
+    /// let hs = lv.handles().collect::<Vec<_>>();
+    /// 
+    /// let pairs = lv.into_iter_with_handles().collect::<Vec<_>>();
+    /// 
+    /// assert_eq!(pairs, vec![(hs[0], 1), (hs[1], 2), (hs[2], 3)]);
+    /// ```
+    #[inline]
+    pub fn into_iter_with_handles(self) -> IntoIterWithHandles<T, I, S> {
+        IntoIterWithHandles(self)
+    }
+
     /// Returns `true` if the list contains no elements.
     /// 
     #[inline]
@@ -550,10 +1584,31 @@ impl<T> LinkedVector<T> {
     /// Returns an iterator over the elements of the list.
     /// 
     #[inline]
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<T, I, S> {
         Iter::new(self)
     }
 
+    /// Returns an iterator over the elements of the list that yields the handle
+    /// of each element along with a reference to it. This avoids a second 
+    /// traversal, or a handle lookup, for each element when both are needed.
+    /// ```
+    /// use linked_vector::*;
+    /// let lv = LinkedVector::from([1, 2, 3]);
+    /// 
+    /// for (h, v) in lv.iter_with_handles() {
+    ///     assert_eq!(lv[h], *v);
+    /// }
+    /// ```
+    #[inline]
+    pub fn iter_with_handles(&self) -> IterWithHandles<'_, T, I, S> {
+        IterWithHandles {
+            hnode : self.head,
+            hrev  : self.back_node().unwrap_or(HNode::BAD),
+            len   : self.len,
+            lv    : self,
+        }
+    }
+
     /// Returns an iterator over the elements of the list. Renders mutable
     /// references to the elements.
     /// ```
@@ -565,10 +1620,109 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(lv, LinkedVector::from([2, 3, 4]));
     /// ```
     #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<T, I, S> {
         IterMut::new(self)
     }
 
+    /// Returns an iterator over the elements of the list starting at the given
+    /// handle and continuing to the end of the list. See 
+    /// [iter_range()](LinkedVector::iter_range) for details.
+    /// ```
+    /// use linked_vector::*;
+    /// let lv = LinkedVector::from([1, 2, 3, 4]);
+    /// let h3 = lv.handle(2).unwrap();
+    /// 
+    /// assert_eq!(lv.iter_from(h3).collect::<Vec<_>>(), vec![&3, &4]);
+    /// ```
+    #[inline]
+    pub fn iter_from(&self, node: impl NodeHandle<T, I>) -> Iter<'_, T, I, S> {
+        let node = node.hnode();
+        self.iter_range(node..)
+    }
+
+    /// Returns an iterator over the elements of the list starting at the given
+    /// handle and continuing to the end of the list. Renders mutable 
+    /// references to the elements. See 
+    /// [iter_range()](LinkedVector::iter_range) for details.
+    /// 
+    #[inline]
+    pub fn iter_from_mut(&mut self, node: impl NodeHandle<T, I>) 
+        -> IterMut<'_, T, I, S> 
+    {
+        let node = node.hnode();
+        self.iter_range_mut(node..)
+    }
+
+    /// Returns an iterator over the elements within the given range of 
+    /// handles. The range can be any of the standard range types bounded by 
+    /// handles, so the end can be inclusive, `h1..=h2`, or exclusive, 
+    /// `h1..h2`. The iterator is double-ended. Panics if the end of the range
+    /// precedes its start. Creating the iterator completes in O(k) time, where
+    /// k is the number of elements in the range.
+    /// ```
+    /// use linked_vector::*;
+    /// let lv = LinkedVector::from([1, 2, 3, 4, 5]);
+    /// let h2 = lv.handle(1).unwrap();
+    /// let h4 = lv.handle(3).unwrap();
+    /// 
+    /// assert_eq!(lv.iter_range(h2..h4).collect::<Vec<_>>(), vec![&2, &3]);
+    /// assert_eq!(lv.iter_range(h2..=h4).rev().collect::<Vec<_>>(), 
+    ///            vec![&4, &3, &2]);
+    /// ```
+    #[inline]
+    pub fn iter_range<R>(&self, range: R) -> Iter<'_, T, I, S> 
+    where
+        R: HandleRange<T, I>,
+    {
+        let (hnode, hrev, len) = self.range_(range);
+        Iter { lv: self, hnode, hrev, len }
+    }
+
+    /// Returns an iterator over the elements within the given range of 
+    /// handles. Renders mutable references to the elements. See 
+    /// [iter_range()](LinkedVector::iter_range) for details.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5]);
+    /// let h2 = lv.handle(1).unwrap();
+    /// let h4 = lv.handle(3).unwrap();
+    /// 
+    /// lv.iter_range_mut(h2..=h4).for_each(|v| *v *= 10);
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![1, 20, 30, 40, 5]);
+    /// ```
+    #[inline]
+    pub fn iter_range_mut<R>(&mut self, range: R) -> IterMut<'_, T, I, S> 
+    where
+        R: HandleRange<T, I>,
+    {
+        let (hnode, hrev, len) = self.range_(range);
+        IterMut { lv: self, hnode, hrev, len }
+    }
+
+    /// Returns an iterator over the elements of the list that yields the handle
+    /// of each element along with a mutable reference to it.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3]);
+    /// let mut hs = vec![];
+    /// 
+    /// for (h, v) in lv.iter_mut_with_handles() {
+    ///     *v *= 10;
+    ///     hs.push(h);
+    /// }
+    /// assert_eq!(lv[hs[2]], 30);
+    /// ```
+    #[inline]
+    pub fn iter_mut_with_handles(&mut self) -> IterMutWithHandles<'_, T, I, S> {
+        IterMutWithHandles {
+            hnode : self.head,
+            hrev  : self.back_node().unwrap_or(HNode::BAD),
+            len   : self.len,
+            lv    : self,
+        }
+    }
+
     /// Returns the length of the list.
     /// 
     #[inline]
@@ -576,8 +1730,125 @@ impl<T> LinkedVector<T> {
         self.len
     }
 
+    /// Moves the element indicated by `node` so it's positioned after the 
+    /// element indicated by `target`. Only the links of the nodes are 
+    /// rewritten, so `node` remains a valid handle to the element. This 
+    /// operation completes in O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4]);
+    /// let h1 = lv.handle(0).unwrap();
+    /// let h3 = lv.handle(2).unwrap();
+    /// 
+    /// lv.move_after(h1, h3);
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![2, 3, 1, 4]);
+    /// assert_eq!(lv[h1], 1);
+    /// ```
+    #[inline]
+    pub fn move_after(&mut self, 
+                      node   : impl NodeHandle<T, I>, 
+                      target : impl NodeHandle<T, I>) 
+    {
+        let node   = node.hnode();
+        let target = target.hnode();
+        #[cfg(feature = "generational-handles")]
+        {
+            self.check_handle(node);
+            self.check_handle(target);
+        }
+        if node != target {
+            self.unlink_(node);
+            let next = self.next_node(target);
+            self.link_(node, next);
+        }
+    }
+
+    /// Moves the element indicated by `node` so it's positioned before the 
+    /// element indicated by `target`. Only the links of the nodes are 
+    /// rewritten, so `node` remains a valid handle to the element. This 
+    /// operation completes in O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4]);
+    /// let h2 = lv.handle(1).unwrap();
+    /// let h4 = lv.handle(3).unwrap();
+    /// 
+    /// lv.move_before(h4, h2);
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![1, 4, 2, 3]);
+    /// assert_eq!(lv[h4], 4);
+    /// ```
+    #[inline]
+    pub fn move_before(&mut self, 
+                       node   : impl NodeHandle<T, I>, 
+                       target : impl NodeHandle<T, I>) 
+    {
+        let node   = node.hnode();
+        let target = target.hnode();
+        #[cfg(feature = "generational-handles")]
+        {
+            self.check_handle(node);
+            self.check_handle(target);
+        }
+        if node != target {
+            self.unlink_(node);
+            self.link_(node, Some(target));
+        }
+    }
+
+    /// Moves the element indicated by `node` to the back of the list. The 
+    /// handle remains valid. This operation completes in O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3]);
+    /// let h1 = lv.front_node().unwrap();
+    /// 
+    /// lv.move_to_back(h1);
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![2, 3, 1]);
+    /// assert_eq!(lv.back_node(), Some(h1));
+    /// ```
+    #[inline]
+    pub fn move_to_back(&mut self, node: impl NodeHandle<T, I>) {
+        let node = node.hnode();
+        #[cfg(feature = "generational-handles")]
+        self.check_handle(node);
+
+        self.unlink_(node);
+        self.link_(node, None);
+    }
+
+    /// Moves the element indicated by `node` to the front of the list. The
+    /// handle remains valid. This is the typical operation performed on an
+    /// element of an LRU list when it's accessed. This operation completes in
+    /// O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3]);
+    /// let h3 = lv.back_node().unwrap();
+    /// 
+    /// lv.move_to_front(h3);
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![3, 1, 2]);
+    /// assert_eq!(lv.front_node(), Some(h3));
+    /// ```
+    #[inline]
+    pub fn move_to_front(&mut self, node: impl NodeHandle<T, I>) {
+        let node = node.hnode();
+        #[cfg(feature = "generational-handles")]
+        self.check_handle(node);
+
+        if node != self.head {
+            self.unlink_(node);
+            let head = self.front_node();
+            self.link_(node, head);
+        }
+    }
+
     /// Returns a handle to the next node in the list, or `None` if the given
-    /// handle is the last node in the list. This operation completes in O(1)
+    /// handle is the last node in the list, or isn't valid for the vector. This
+    /// operation completes in O(1) time.
     /// ```
     /// use linked_vector::*;
     /// let mut lv = LinkedVector::new();
@@ -588,9 +1859,11 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(lv.next_node(h1), Some(h2));
     /// ```
     #[inline]
-    pub fn next_node(&self, node: HNode) -> Option<HNode> {
-        let next = self.get_(node).next;
-        if next == BAD_HANDLE {
+    pub fn next_node(&self, node: impl NodeHandle<T, I>) -> Option<HNode<I>> {
+        let node = node.hnode();
+        self.validate_handle(node).ok()?;
+        let next = self.next_(node);
+        if next == HNode::BAD {
             None
         } else {
             Some(next)
@@ -602,8 +1875,9 @@ impl<T> LinkedVector<T> {
     /// completes in O(1) time.
     /// 
     #[inline]
-    pub fn next_value(&self, node: HNode) -> Option<&T> {
-        self.next_node(node).and_then(|n| self.get_(n).value.as_ref())
+    pub fn next_value(&self, node: impl NodeHandle<T, I>) -> Option<&T> {
+        let node = node.hnode();
+        self.next_node(node).and_then(|n| self.get_(n).value())
     }
 
     /// Returns a mutable reference to the next element's value in the list, or
@@ -611,13 +1885,16 @@ impl<T> LinkedVector<T> {
     /// completes in O(1) time.
     /// 
     #[inline]
-    pub fn next_value_mut(&mut self, node: HNode) -> Option<&mut T> {
-        self.next_node(node).and_then(move |n| self.get_mut_(n).value.as_mut())
+    pub fn next_value_mut(&mut self, node: impl NodeHandle<T, I>) 
+        -> Option<&mut T> 
+    {
+        let node = node.hnode();
+        self.next_node(node).and_then(move |n| self.get_mut_(n).value_mut())
     }
 
     /// Returns a handle to the previous node in the list, or `None` if the 
-    /// given handle is the first node in the list. This operation completes in
-    /// O(1) time.
+    /// given handle is the first node in the list, or isn't valid for the 
+    /// vector. This operation completes in O(1) time.
     /// ```
     /// use linked_vector::*;
     /// let mut lv = LinkedVector::new();
@@ -628,9 +1905,11 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(lv.prev_node(h2), Some(h1));
     /// ```
     #[inline]
-    pub fn prev_node(&self, node: HNode) -> Option<HNode> {
+    pub fn prev_node(&self, node: impl NodeHandle<T, I>) -> Option<HNode<I>> {
+        let node = node.hnode();
+        self.validate_handle(node).ok()?;
         if node != self.head {
-            Some(self.get_(node).prev)
+            Some(self.prev_(node))
         } else {
             None
         }
@@ -641,8 +1920,9 @@ impl<T> LinkedVector<T> {
     /// completes in O(1) time.
     /// 
     #[inline]
-    pub fn prev_value(&self, node: HNode) -> Option<&T> {
-        self.prev_node(node).and_then(|n| self.get_(n).value.as_ref())
+    pub fn prev_value(&self, node: impl NodeHandle<T, I>) -> Option<&T> {
+        let node = node.hnode();
+        self.prev_node(node).and_then(|n| self.get_(n).value())
     }
 
     /// Returns a mutable reference to the previous element's value in the list,
@@ -650,8 +1930,11 @@ impl<T> LinkedVector<T> {
     /// operation completes in O(1) time.
     /// 
     #[inline]
-    pub fn prev_value_mut(&mut self, node: HNode) -> Option<&mut T> {
-        self.prev_node(node).and_then(move |n| self.get_mut_(n).value.as_mut())
+    pub fn prev_value_mut(&mut self, node: impl NodeHandle<T, I>) 
+        -> Option<&mut T> 
+    {
+        let node = node.hnode();
+        self.prev_node(node).and_then(move |n| self.get_mut_(n).value_mut())
     }
 
     /// Pops the last element of the vector. Returns `None` if the vector is
@@ -664,7 +1947,7 @@ impl<T> LinkedVector<T> {
     /// ```
     #[inline]
     pub fn pop_back(&mut self) -> Option<T> {
-        self.remove_(None)
+        self.remove_compacting_(None)
     }
 
     /// Pops the first element of the vector. Returns `None` if the vector is
@@ -680,7 +1963,7 @@ impl<T> LinkedVector<T> {
         if self.is_empty() {
             None
         } else {
-            self.remove_(Some(self.head))
+            self.remove_compacting_(Some(self.head))
         }
     }
 
@@ -696,7 +1979,7 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(lv.next_node(h1), Some(h2));
     /// ```
     #[inline]
-    pub fn push_back(&mut self, value: T) -> HNode {
+    pub fn push_back(&mut self, value: T) -> HNode<I> {
         self.insert_(None, value)
     }
 
@@ -712,7 +1995,7 @@ impl<T> LinkedVector<T> {
     /// assert_eq!(lv.next_node(h2), Some(h1));
     /// ```
     #[inline]
-    pub fn push_front(&mut self, value: T) -> HNode {
+    pub fn push_front(&mut self, value: T) -> HNode<I> {
         if self.is_empty() {
             self.insert_(None, value)
         } else {
@@ -720,6 +2003,47 @@ impl<T> LinkedVector<T> {
         }
     }
 
+    /// Returns the policy that chooses which vacant node is reused when an 
+    /// element is added. See [RecyclePolicy].
+    /// 
+    #[inline]
+    pub fn recycle_policy(&self) -> RecyclePolicy {
+        self.policy
+    }
+
+    /// Reserves capacity for at least `additional` more nodes in the internal
+    /// vector, so that many elements can be added without reallocating. Nodes
+    /// waiting to be recycled aren't counted. Panics if the capacity overflows
+    /// or the storage can't hold them, and aborts if allocation fails.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3]);
+    /// 
+    /// lv.reserve(10);
+    /// 
+    /// assert!(lv.capacity() >= 13);
+    /// ```
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    /// Reserves capacity for exactly `additional` more nodes in the internal
+    /// vector, without deliberately over-allocating. See 
+    /// [reserve()](LinkedVector::reserve).
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3]);
+    /// 
+    /// lv.reserve_exact(10);
+    /// 
+    /// assert!(lv.capacity() >= 13);
+    /// ```
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.vec.reserve_exact(additional);
+    }
+
     /// Removes the element indicated by the handle, `node`. Returns the element
     /// if the handle is valid, or panics otherwise. This operation completes in
     /// O(1) time. With the `optionless-accessors` feature disabled, this method
@@ -736,13 +2060,15 @@ impl<T> LinkedVector<T> {
     /// ```
     #[inline]
     #[cfg(feature = "optionless-accessors")]
-    pub fn remove(&mut self, node: HNode) -> T {
-        self.remove_(Some(node)).unwrap()
+    pub fn remove(&mut self, node: impl NodeHandle<T, I>) -> T {
+        let node = node.hnode();
+        self.remove_compacting_(Some(node)).unwrap()
     }
 
     /// Removes the element indicated by the handle, `node`. Returns the element
-    /// if the handle is valid, or `None` otherwise. This operation completes in
-    /// O(1) time. With the `"optionless-accessors"` feature enabled, this 
+    /// if the handle is valid, or `None` if it's invalid, expired, or foreign
+    /// to the vector. This operation completes in O(1) time. With the 
+    /// `"optionless-accessors"` feature enabled, this 
     /// method returns its value directly, not wrapped in an `Option`,
     /// see [usage notes](./index.html#feature-optionless-accessors).
     /// ```
@@ -756,8 +2082,132 @@ impl<T> LinkedVector<T> {
     /// ```    
     #[inline]
     #[cfg(not(feature = "optionless-accessors"))]
-    pub fn remove(&mut self, node: HNode) -> Option<T> {
-        self.remove_(Some(node))
+    pub fn remove(&mut self, node: impl NodeHandle<T, I>) -> Option<T> {
+        let node = node.hnode();
+        self.validate_handle(node).ok()?;
+        self.remove_compacting_(Some(node))
+    }
+
+    /// Retains only the elements for which the predicate returns `true`. The
+    /// nodes of the other elements are unlinked and placed in the recycle 
+    /// list during a single traversal. Handles to the retained elements remain
+    /// valid. This operation completes in O(n) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5, 6]);
+    /// let h6 = lv.back_node().unwrap();
+    /// 
+    /// lv.retain(|&v| v % 2 == 0);
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![2, 4, 6]);
+    /// assert_eq!(lv[h6], 6);
+    /// ```
+    #[inline]
+    pub fn retain<F>(&mut self, mut pred: F) 
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_(|_, v| pred(v));
+    }
+
+    /// Retains only the elements for which the predicate returns `true`, 
+    /// passing a mutable reference to each element to the predicate. See 
+    /// [retain()](LinkedVector::retain) for more details.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5, 6]);
+    /// 
+    /// lv.retain_mut(|v| { *v *= 10; *v > 30 });
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![40, 50, 60]);
+    /// ```
+    #[inline]
+    pub fn retain_mut<F>(&mut self, mut pred: F) 
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.retain_(|_, v| pred(v));
+    }
+
+    /// Retains only the elements for which the predicate returns `true`, 
+    /// passing the handle of each element along with a reference to it. This
+    /// allows external structures that hold handles to be updated as elements
+    /// are removed. See [retain()](LinkedVector::retain) for more details.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4]);
+    /// let mut removed = vec![];
+    /// 
+    /// lv.retain_with_handle(|h, &v| {
+    ///     if v < 3 { removed.push(h); }
+    ///     v >= 3
+    /// });
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![3, 4]);
+    /// assert_eq!(removed.len(), 2);
+    /// ```
+    #[inline]
+    pub fn retain_with_handle<F>(&mut self, mut pred: F) 
+    where
+        F: FnMut(HNode<I>, &T) -> bool,
+    {
+        self.retain_(|h, v| pred(h, v));
+    }
+
+    /// Sets or clears the policy that compacts the vector automatically when 
+    /// removing elements leaves it too fragmented. Unlike the recycle policy,
+    /// it isn't carried over to vectors made from this one. See 
+    /// [CompactionPolicy].
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from_iter(0..100);
+    /// lv.set_compaction_policy(Some(CompactionPolicy::new(0.25)));
+    /// 
+    /// for _ in 0..26 {
+    ///     lv.pop_back();
+    /// }
+    /// assert_eq!(lv.vacant_count(), 0);
+    /// 
+    /// lv.set_compaction_policy(None);
+    /// 
+    /// assert!(lv.compaction_policy().is_none());
+    /// ```
+    pub fn set_compaction_policy(&mut self, 
+                                 policy: Option<CompactionPolicy<I>>) 
+    {
+        self.compaction = policy.map(Box::new);
+    }
+
+    /// Sets the policy that chooses which vacant node is reused when an element
+    /// is added. Vectors made from this one, by methods like 
+    /// [split_off()](LinkedVector::split_off) or by cloning it, use the same
+    /// policy. Switching from `Lifo` to another policy leaves the recycle list
+    /// to be sorted when the next element is added. This operation completes
+    /// in O(1) time. See [RecyclePolicy].
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5]);
+    /// lv.set_recycle_policy(RecyclePolicy::NearNeighbor);
+    /// 
+    /// let h2 = lv.handle(1).unwrap();
+    /// let h5 = lv.handle(4).unwrap();
+    /// 
+    /// lv.remove(h2);
+    /// lv.remove(h5);
+    /// 
+    /// // The vacant node next to the tail is taken over the older one.
+    /// let h6 = lv.push_back(6);
+    /// 
+    /// assert_eq!(h6.into_raw() as u32, 4);
+    /// assert_eq!(lv.recycle_policy(), RecyclePolicy::NearNeighbor);
+    /// ```
+    pub fn set_recycle_policy(&mut self, policy: RecyclePolicy) {
+        self.policy = policy;
+        self.unsorted = if policy == RecyclePolicy::Lifo {
+            0
+        } else {
+            self.vacant_count()
+        };
     }
 
     /// Sorts the elemements in place in ascending order. Previously held 
@@ -807,109 +2257,486 @@ impl<T> LinkedVector<T> {
         self.sort_by_(compare, true)
     }
 
-    /// Sorts the elemements in place in using the provided key extraction
-    /// function. See [sort()](LinkedVector::sort) for more details.
+    /// Sorts the elemements in place in using the provided key extraction
+    /// function. See [sort()](LinkedVector::sort) for more details.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5]);
+    /// 
+    /// lv.sort_by_key(|k| -k);
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![5, 4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn sort_by_key<K, F>(&mut self, mut key: F) 
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by_(|a, b| key(a).cmp(&key(b)), true);
+    }
+
+    /// Sorts the elemements in place in ascending order. Previously held
+    /// handles will still be valid and reference the same elements (with the
+    /// same values) as before.  Only the `next` and `prev` fields of the nodes
+    /// are modified in the list. Uses Rust's unstable sort internally and
+    /// requires some auxiliary memory for a temporary handle list.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([5, 4, 3, 2, 1, 0]);
+    /// 
+    /// lv.sort_unstable();
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![0, 1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable(&mut self) 
+    where
+        T: Ord
+    {
+        self.sort_by_(|a, b| a.cmp(b), false);
+    }
+
+    /// Sorts the elemements in place using the provided comparison function.
+    /// See [sort_unstable()](LinkedVector::sort_unstable) for more details.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5]);
+    /// 
+    /// lv.sort_unstable_by(|a, b| b.cmp(a));
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![5, 4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable_by<F>(&mut self , compare: F) 
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.sort_by_(compare, false);
+    }
+
+    /// Sorts the elemements in place in using the provided key extraction
+    /// function. See [sort_unstable()](LinkedVector::sort_unstable) for more
+    /// details.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5]);
+    /// 
+    /// lv.sort_unstable_by_key(|k| -k);
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![5, 4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut key: F) 
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by_(|a, b| key(a).cmp(&key(b)), false);
+    }
+
+    /// Splits the list in two at the element indicated by `at`. The returned 
+    /// vector holds `at` and every element after it, while `self` keeps the 
+    /// elements before it. Handles to the elements remaining in `self` stay
+    /// valid, and the handles of the moved elements expire. The moved 
+    /// elements are placed contiguously and in order in the new vector, so
+    /// their new handles are available in order from its 
+    /// [handles()](LinkedVector::handles). This operation completes in O(k)
+    /// time where k is the number of elements moved.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5]);
+    /// let h2  = lv1.handle(1).unwrap();
+    /// let h4  = lv1.handle(3).unwrap();
+    /// 
+    /// let lv2 = lv1.split_off(h4);
+    /// 
+    /// assert_eq!(lv1.to_vec(), vec![1, 2, 3]);
+    /// assert_eq!(lv2.to_vec(), vec![4, 5]);
+    /// assert_eq!(lv1[h2], 2);
+    /// ```
+    #[must_use]
+    pub fn split_off(&mut self, at: impl NodeHandle<T, I>) -> Self {
+        let at = at.hnode();
+        #[cfg(feature = "generational-handles")]
+        self.check_handle(at);
+
+        let mut other = self.new_like_(0);
+
+        if at == self.head {
+            self.head = HNode::BAD;
+        } else {
+            let hprev = self.prev_(at);
+            self.get_mut_(hprev).next = I::NONE;
+            self.get_mut_(self.head).prev = hprev.0;
+        }
+        let mut hnode = at;
+
+        while hnode != HNode::BAD {
+            let hnext = self.next_(hnode);
+            let value = self.get_mut_(hnode).take().unwrap();
+            self.push_recyc(hnode);
+            self.len -= 1;
+            other.push_back(value);
+            hnode = hnext;
+        }
+        other
+    }
+
+    /// Splits the list in two after the element indicated by `at`. The 
+    /// returned vector holds every element after `at`, while `self` keeps `at`
+    /// and the elements before it. See [split_off()](LinkedVector::split_off)
+    /// for which handles remain valid. This operation completes in O(k) time
+    /// where k is the number of elements moved.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5]);
+    /// let h3  = lv1.handle(2).unwrap();
+    /// 
+    /// let lv2 = lv1.split_off_after(h3);
+    /// 
+    /// assert_eq!(lv1.to_vec(), vec![1, 2, 3]);
+    /// assert_eq!(lv2.to_vec(), vec![4, 5]);
+    /// ```
+    #[must_use]
+    pub fn split_off_after(&mut self, at: impl NodeHandle<T, I>) -> Self {
+        let at    = at.hnode();
+        let hnext = self.next_(at);
+        if hnext != HNode::BAD {
+            self.split_off(hnext)
+        } else {
+            self.new_like_(0)
+        }
+    }
+
+    /// Returns a vector containing the elements of the list. This operation
+    /// completes in O(n) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let lv = LinkedVector::from([1, 2, 3]);
+    /// 
+    /// assert_eq!(lv.to_vec(), vec![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T> 
+    where
+        T: Clone
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Creates a cursor starting at the given node. Unlike 
+    /// [cursor()](LinkedVector::cursor), this method doesn't panic on a bad 
+    /// handle; an error describing the problem with the handle is returned
+    /// instead. Returns `LinkedVectorError::EmptyList` if the vector is empty.
+    /// This operation completes in O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let lv = LinkedVector::from([1, 2, 3]);
+    /// let h2 = lv.handle(1).unwrap();
+    /// 
+    /// assert_eq!(*lv.try_cursor(h2).unwrap(), 2);
+    /// assert!(lv.try_cursor(HNode::default()).is_err());
+    /// ```
+    #[inline]
+    pub fn try_cursor(&self, node: impl NodeHandle<T, I>) 
+        -> Result<Cursor<'_, T, I, S>, LinkedVectorError> 
+    {
+        let node = node.hnode();
+        self.validate_handle(node)?;
+        Ok(Cursor::new(self, node))
+    }
+
+    /// Creates a mutable cursor starting at the given node. Unlike 
+    /// [cursor_mut()](LinkedVector::cursor_mut), this method doesn't panic on
+    /// a bad handle; an error describing the problem with the handle is 
+    /// returned instead. This operation completes in O(1) time.
+    /// 
+    #[inline]
+    pub fn try_cursor_mut(&mut self, node: impl NodeHandle<T, I>) 
+        -> Result<CursorMut<'_, T, I, S>, LinkedVectorError> 
+    {
+        let node = node.hnode();
+        self.validate_handle(node)?;
+        Ok(CursorMut::new(self, node))
+    }
+
+    /// Provides a reference to the element indicated by the given handle, or
+    /// an error describing why the handle can't be used with this vector. This
+    /// method doesn't panic on bad, foreign, or expired handles. This 
+    /// operation completes in O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3]);
+    /// let hnode = lv.push_front(42);
+    /// 
+    /// assert_eq!(lv.try_get(hnode), Ok(&42));
+    /// 
+    /// lv.remove(hnode);
+    /// 
+    /// assert_eq!(lv.try_get(hnode), Err(LinkedVectorError::ExpiredHandle));
+    /// ```
+    #[inline]
+    pub fn try_get(&self, node: impl NodeHandle<T, I>) 
+        -> Result<&T, LinkedVectorError> 
+    {
+        let node = node.hnode();
+        self.validate_handle(node)?;
+        Ok(self.vec[node.0.index()].value().unwrap())
+    }
+
+    /// Provides a mutable reference to the element indicated by the given 
+    /// handle, or an error describing why the handle can't be used with this 
+    /// vector. This operation completes in O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::new();
+    /// let hnode = lv.push_front(0);
+    /// 
+    /// *lv.try_get_mut(hnode).unwrap() = 42;
+    /// 
+    /// assert_eq!(lv[hnode], 42);
+    /// ```
+    #[inline]
+    pub fn try_get_mut(&mut self, node: impl NodeHandle<T, I>) 
+        -> Result<&mut T, LinkedVectorError> 
+    {
+        let node = node.hnode();
+        self.validate_handle(node)?;
+        Ok(self.vec[node.0.index()].value_mut().unwrap())
+    }
+
+    /// Inserts a new element at the position indicated by the handle, `node`.
+    /// Returns a handle to the newly inserted element, or a `TryInsertError`
+    /// describing why `node` can't be used with this vector, or why room 
+    /// couldn't be made for the element. The error gives `value` back. 
+    /// Allocation failure is reported as `LinkedVectorError::ReserveFailed`
+    /// rather than aborting. This operation completes in O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3]);
+    /// let h1 = lv.front_node().unwrap();
+    /// 
+    /// let h2 = lv.try_insert(h1, 42).unwrap();
+    /// 
+    /// assert_eq!(lv.front_node(), Some(h2));
+    /// assert!(lv.try_insert(HNode::default(), 99).is_err());
+    /// ```
+    #[inline]
+    pub fn try_insert(&mut self, node: impl NodeHandle<T, I>, value: T) 
+        -> Result<HNode<I>, TryInsertError<T>> 
+    {
+        let node = node.hnode();
+        if let Err(error) = self.validate_handle(node) {
+            return Err(TryInsertError::new(value, error));
+        }
+        Ok(self.try_insert_(Some(node), value)?)
+    }
+
+    /// Inserts a new element after the one indicated by the handle, `node`.
+    /// Returns a handle to the newly inserted element, or a `TryInsertError`
+    /// describing why `node` can't be used with this vector, or why room 
+    /// couldn't be made for the element. The error gives `value` back. This
+    /// operation completes in O(1) time.
+    /// ```
+    /// use linked_vector::*;
+    /// let mut lv = LinkedVector::from([1, 2, 3]);
+    /// let h1 = lv.front_node().unwrap();
+    /// 
+    /// let h2 = lv.try_insert_after(h1, 42).unwrap();
+    /// 
+    /// assert_eq!(lv.next_node(h1), Some(h2));
+    /// ```
+    #[inline]
+    pub fn try_insert_after(&mut self, node: impl NodeHandle<T, I>, value: T) 
+        -> Result<HNode<I>, TryInsertError<T>> 
+    {
+        let node = node.hnode();
+        if let Err(error) = self.validate_handle(node) {
+            return Err(TryInsertError::new(value, error));
+        }
+        Ok(self.try_insert_(self.next_node(node), value)?)
+    }
+
+    /// Pushes a new element to the back of the list. Returns a handle to the
+    /// newly inserted element, or an error if room couldn't be made for it,
+    /// either because allocation failed or because the storage is full and 
+    /// can't grow, as with an [ArrayLinkedVector]. The element is given back
+    /// in the error. This operation completes in O(1) time.
     /// ```
     /// use linked_vector::*;
-    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5]);
+    /// let mut lv = ArrayLinkedVector::<i32, 2>::new_array();
     /// 
-    /// lv.sort_by_key(|k| -k);
+    /// let h1 = lv.try_push_back(1).unwrap();
+    /// let h2 = lv.try_push_back(2).unwrap();
     /// 
-    /// assert_eq!(lv.to_vec(), vec![5, 4, 3, 2, 1]);
+    /// assert_eq!(lv.try_push_back(3).unwrap_err().into_inner(), 3);
+    /// assert_eq!(lv.next_node(h1), Some(h2));
     /// ```
     #[inline]
-    pub fn sort_by_key<K, F>(&mut self, mut key: F) 
-    where
-        K: Ord,
-        F: FnMut(&T) -> K,
+    pub fn try_push_back(&mut self, value: T) 
+        -> Result<HNode<I>, TryPushError<T>> 
     {
-        self.sort_by_(|a, b| key(a).cmp(&key(b)), true);
+        self.try_insert_(None, value)
     }
 
-    /// Sorts the elemements in place in ascending order. Previously held
-    /// handles will still be valid and reference the same elements (with the
-    /// same values) as before.  Only the `next` and `prev` fields of the nodes
-    /// are modified in the list. Uses Rust's unstable sort internally and
-    /// requires some auxiliary memory for a temporary handle list.
+    /// Pushes a new element to the front of the list. Returns a handle to the
+    /// newly inserted element, or an error if room couldn't be made for it,
+    /// either because allocation failed or because the storage is full and 
+    /// can't grow, as with an [ArrayLinkedVector]. The element is given back
+    /// in the error. This operation completes in O(1) time.
     /// ```
     /// use linked_vector::*;
-    /// let mut lv = LinkedVector::from([5, 4, 3, 2, 1, 0]);
+    /// let mut lv = ArrayLinkedVector::<i32, 2>::new_array();
     /// 
-    /// lv.sort_unstable();
+    /// let h1 = lv.try_push_front(1).unwrap();
+    /// let h2 = lv.try_push_front(2).unwrap();
     /// 
-    /// assert_eq!(lv.to_vec(), vec![0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(lv.try_push_front(3).unwrap_err().into_inner(), 3);
+    /// assert_eq!(lv.next_node(h2), Some(h1));
     /// ```
     #[inline]
-    pub fn sort_unstable(&mut self) 
-    where
-        T: Ord
+    pub fn try_push_front(&mut self, value: T) 
+        -> Result<HNode<I>, TryPushError<T>> 
     {
-        self.sort_by_(|a, b| a.cmp(b), false);
+        if self.is_empty() {
+            self.try_insert_(None, value)
+        } else {
+            self.try_insert_(Some(self.head), value)
+        }
     }
 
-    /// Sorts the elemements in place using the provided comparison function.
-    /// See [sort_unstable()](LinkedVector::sort_unstable) for more details.
+    /// Removes the element indicated by the handle, `node`, and returns it. If
+    /// the handle can't be used with this vector, an error describing why is
+    /// returned instead of panicking. This operation completes in O(1) time.
     /// ```
     /// use linked_vector::*;
-    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5]);
-    /// 
-    /// lv.sort_unstable_by(|a, b| b.cmp(a));
+    /// let mut lv = LinkedVector::from([1, 2, 3]);
+    /// let h2 = lv.handle(1).unwrap();
     /// 
-    /// assert_eq!(lv.to_vec(), vec![5, 4, 3, 2, 1]);
+    /// assert_eq!(lv.try_remove(h2), Ok(2));
+    /// assert_eq!(lv.try_remove(h2), Err(LinkedVectorError::ExpiredHandle));
     /// ```
     #[inline]
-    pub fn sort_unstable_by<F>(&mut self , compare: F) 
-    where
-        F: FnMut(&T, &T) -> Ordering,
+    pub fn try_remove(&mut self, node: impl NodeHandle<T, I>) 
+        -> Result<T, LinkedVectorError> 
     {
-        self.sort_by_(compare, false);
+        let node = node.hnode();
+        self.validate_handle(node)?;
+        Ok(self.remove_compacting_(Some(node)).unwrap())
     }
 
-    /// Sorts the elemements in place in using the provided key extraction
-    /// function. See [sort_unstable()](LinkedVector::sort_unstable) for more
-    /// details.
+    /// Reserves capacity for at least `additional` more nodes in the internal
+    /// vector, or returns an error if allocation fails or the storage can't
+    /// grow, instead of aborting. See [reserve()](LinkedVector::reserve).
     /// ```
     /// use linked_vector::*;
-    /// let mut lv = LinkedVector::from([1, 2, 3, 4, 5]);
-    /// 
-    /// lv.sort_unstable_by_key(|k| -k);
+    /// let mut lv1 = LinkedVector::<i32>::new();
+    /// let mut lv2 = ArrayLinkedVector::<i32, 4>::new_array();
     /// 
-    /// assert_eq!(lv.to_vec(), vec![5, 4, 3, 2, 1]);
+    /// assert!(lv1.try_reserve(10).is_ok());
+    /// assert!(lv1.capacity() >= 10);
+    /// assert!(lv2.try_reserve(5).is_err());
+    /// assert!(lv1.try_reserve(usize::MAX).is_err());
     /// ```
     #[inline]
-    pub fn sort_unstable_by_key<K, F>(&mut self, mut key: F) 
-    where
-        K: Ord,
-        F: FnMut(&T) -> K,
+    pub fn try_reserve(&mut self, additional: usize) 
+        -> Result<(), TryReserveError> 
     {
-        self.sort_by_(|a, b| key(a).cmp(&key(b)), false);
+        self.vec.try_reserve(additional)
     }
 
-    /// Returns a vector containing the elements of the list. This operation
-    /// completes in O(n) time.
+    /// Returns the number of vacant nodes in the internal vector, which are
+    /// waiting to be reused for new elements. This operation completes in O(1)
+    /// time.
     /// ```
     /// use linked_vector::*;
-    /// let lv = LinkedVector::from([1, 2, 3]);
+    /// let mut lv = LinkedVector::from([1, 2, 3]);
     /// 
-    /// assert_eq!(lv.to_vec(), vec![1, 2, 3]);
+    /// lv.pop_front();
+    /// 
+    /// assert_eq!(lv.vacant_count(), 1);
     /// ```
     #[inline]
-    pub fn to_vec(&self) -> Vec<T> 
-    where
-        T: Clone
-    {
-        self.iter().cloned().collect()
+    pub fn vacant_count(&self) -> usize {
+        self.vec.len() - self.len
+    }
+
+    /// Moves the nodes of `other` to the end of the internal vector, rewriting
+    /// their links with the offset they're moved by, then joins the lists and
+    /// the recycle lists of both vectors. Returns the offset. This operation
+    /// completes in O(n) time where n is the number of nodes in `other`.
+    /// 
+    fn append_(&mut self, other: &mut Self) -> usize {
+        let offset = self.vec.len();
+
+        // Room is made for every node of `other` before any is moved, so a 
+        // panic here leaves both vectors intact.
+        assert!(other.vec.len() <= I::NONE.index() - offset, 
+                "Index exceeds the capacity of the index type.");
+
+        self.vec.reserve(other.vec.len());
+
+        let rebase = |link: I| {
+            if link == I::NONE {
+                link
+            } else {
+                I::from_usize(link.index() + offset)
+            }
+        };
+        for i in 0..other.vec.len() {
+            let mut node = other.vec[i].move_out();
+            node.next = rebase(node.next);
+            node.prev = rebase(node.prev);
+
+            // The slot may have been released by this vector before, so the 
+            // node can't take a generation its old handles would match.
+            #[cfg(feature = "generational-handles")]
+            { node.gen = node.gen.max(self.floor); }
+
+            self.vec.push(node);
+        }
+        if !other.is_empty() {
+            let ohead = self.rebase_(other.head, offset);
+            let otail = self.prev_(ohead);
+            if self.is_empty() {
+                self.head = ohead;
+            } else {
+                let htail = self.prev_(self.head);
+                self.get_mut_(htail).next = ohead.0;
+                self.get_mut_(ohead).prev = htail.0;
+                self.get_mut_(self.head).prev = otail.0;
+            }
+            self.len += other.len;
+        }
+        if other.recyc != HNode::BAD {
+            let orecyc = self.rebase_(other.recyc, offset);
+            let mut ilast = orecyc.0.index();
+            while self.vec[ilast].next != I::NONE {
+                ilast = self.vec[ilast].next.index();
+            }
+            self.vec[ilast].next = self.recyc.0;
+            self.recyc = orecyc;
+
+            if self.policy != RecyclePolicy::Lifo {
+                self.unsorted = self.vacant_count();
+            }
+        }
+        // The vacant nodes left in `other` keep their generations, so 
+        // `clear()` keeps the old handles to them expired.
+        other.clear();
+        offset
     }
 
     /// Returns a reference to the last node. Returns `None` if the list is
     /// empty. This operation completes in O(1) time.
     /// 
     #[inline]
-    fn back_(&self) -> Option<&Node<T>> {
+    fn back_(&self) -> Option<&Node<T, I>> {
         if self.is_empty() {
             None
         } else {
-            Some(self.get_(self.get_(self.head).prev))
+            Some(self.get_(self.prev_(self.head)))
         }
     }
 
@@ -917,7 +2744,7 @@ impl<T> LinkedVector<T> {
     /// empty. This operation completes in O(1) time.
     /// 
     #[inline]
-    fn front_(&self) -> Option<&Node<T>> {
+    fn front_(&self) -> Option<&Node<T, I>> {
         if self.is_empty() {
             None
         } else {
@@ -927,38 +2754,129 @@ impl<T> LinkedVector<T> {
 
     /// Inserts `value` before the element indicated by `node`. If `node` is
     /// `None`, the element is inserted at the end of the list. Returns a handle
-    /// to the newly inserted element. This operation completes in O(1) time.
+    /// to the newly inserted element. Panics if the storage is full. This 
+    /// operation completes in O(1) time.
     /// 
     #[inline]
-    pub(crate) fn insert_(&mut self, node: Option<HNode>, value: T) -> HNode {
+    pub(crate) fn insert_(&mut self, node: Option<HNode<I>>, value: T) 
+        -> HNode<I> 
+    {
+        #[cfg(feature = "checked-handles")]
         if self.is_empty() {
-            #[cfg(debug_assertions)]
             assert!(node.is_none(), "Empty list has no handles.");
-            let hnew = self.new_node(value);
-            self.head = hnew; 
-            self.get_mut_(hnew).prev = hnew;
-            self.len += 1;
-            hnew 
-        } else {
-            let hnew = self.new_node(value);
-            if let Some(hnode) = node {
-                let hprev = self.get_(hnode).prev;
-                self.get_mut_(hnew).prev = hprev;
-                self.get_mut_(hnew).next = hnode;
-                self.get_mut_(hnode).prev = hnew;
-                if hnode == self.head {
-                    self.head = hnew;
-                } else {
-                    self.get_mut_(hprev).next = hnew;
-                }
+        }
+        let near = match node {
+            Some(hnode) => Some(hnode),
+            None => self.back_node(),
+        };
+        let hnew = self.new_node(value, near);
+        self.link_(hnew, node);
+        self.len += 1;
+        hnew
+    }
+
+    /// Inserts `value` before the element indicated by `node`, or at the end
+    /// of the list if `node` is `None`. Room for a new node is reserved first
+    /// unless one can be recycled, so the insertion can't fail afterward. 
+    /// Returns a handle to the newly inserted element, or the error from the
+    /// reservation along with `value`. This operation completes in O(1) time.
+    /// 
+    #[inline]
+    fn try_insert_(&mut self, node: Option<HNode<I>>, value: T) 
+        -> Result<HNode<I>, TryPushError<T>> 
+    {
+        if self.recyc == HNode::BAD {
+            if let Err(error) = self.vec.try_reserve(1) {
+                return Err(TryPushError::new(value, error));
+            }
+        }
+        Ok(self.insert_(node, value))
+    }
+
+    /// Links the detached node, `hnew`, into the list before the node 
+    /// indicated by `node`. If `node` is `None`, `hnew` is linked in at the
+    /// end of the list. The length of the list isn't updated. This operation
+    /// completes in O(1) time.
+    /// 
+    #[inline]
+    fn link_(&mut self, hnew: HNode<I>, node: Option<HNode<I>>) {
+        if self.head == HNode::BAD {
+            self.head = hnew;
+            self.get_mut_(hnew).prev = hnew.0;
+            self.get_mut_(hnew).next = I::NONE;
+        } else if let Some(hnode) = node {
+            let hprev = self.prev_(hnode);
+            self.get_mut_(hnew).prev = hprev.0;
+            self.get_mut_(hnew).next = hnode.0;
+            self.get_mut_(hnode).prev = hnew.0;
+            if hnode == self.head {
+                self.head = hnew;
             } else {
-                let hnode = self.get_(self.head).prev;
-                self.get_mut_(hnode).next = hnew;
-                self.get_mut_(hnew).prev  = hnode;
-                self.get_mut_(self.head).prev = hnew;
+                self.get_mut_(hprev).next = hnew.0;
             }
-            self.len += 1;
-            hnew
+        } else {
+            let hnode = self.prev_(self.head);
+            self.get_mut_(hnode).next = hnew.0;
+            self.get_mut_(hnew).prev  = hnode.0;
+            self.get_mut_(hnew).next  = I::NONE;
+            self.get_mut_(self.head).prev = hnew.0;
+        }
+    }
+
+    /// Resolves a range of handles into the handles of its first and last 
+    /// elements, and the number of elements within it. The handles are 
+    /// `HNode::BAD` if the range is empty. Panics if the end of the range 
+    /// precedes its start. This operation completes in O(k) time where k is the
+    /// number of elements in the range.
+    /// 
+    pub(crate) fn range_<R>(&self, range: R) -> (HNode<I>, HNode<I>, usize) 
+    where
+        R: HandleRange<T, I>,
+    {
+        let range = range.bounds();
+        if self.is_empty() {
+            return (HNode::BAD, HNode::BAD, 0);
+        }
+        if let (Bound::Unbounded, Bound::Unbounded) = (range.start_bound(), 
+                                                       range.end_bound()) {
+            return (self.head, self.prev_(self.head), self.len);
+        }
+        let first = match range.start_bound() {
+            Bound::Included(&hnode) => hnode,
+            Bound::Excluded(&hnode) => {
+                let hnext = self.next_(hnode);
+                if hnext == HNode::BAD {
+                    return (HNode::BAD, HNode::BAD, 0);
+                }
+                hnext
+            },
+            Bound::Unbounded => self.head,
+        };
+        // The node after the last one in the range.
+        let stop = match range.end_bound() {
+            Bound::Included(&hnode) => self.next_(hnode),
+            Bound::Excluded(&hnode) => {
+                #[cfg(feature = "generational-handles")]
+                self.check_handle(hnode);
+                hnode
+            },
+            Bound::Unbounded => HNode::BAD,
+        };
+        let mut hnode = first;
+        let mut hlast = HNode::BAD;
+        let mut len   = 0;
+
+        while hnode != stop {
+            assert!(hnode != HNode::BAD, 
+                    "The end of the range precedes its start.");
+            hlast = hnode;
+            hnode = self.next_(hnode);
+            len  += 1;
+        }
+        if len == 0 {
+            (HNode::BAD, HNode::BAD, 0)
+        } else {
+            (first, hlast, len)
         }
     }
 
@@ -967,129 +2885,467 @@ impl<T> LinkedVector<T> {
     /// O(1) time.
     /// 
     #[inline]
-    pub(crate) fn remove_(&mut self, node: Option<HNode>) -> Option<T> {
+    pub(crate) fn remove_(&mut self, node: Option<HNode<I>>) -> Option<T> {
         if self.is_empty() {
-            #[cfg(debug_assertions)]
+            #[cfg(feature = "checked-handles")]
             assert!(node.is_none(), "Empty list has no handles.");
             None
         } else {
-            let hnode = node.unwrap_or(self.get_(self.head).prev);
-            if self.len > 1 {
-                let hprev = self.get_(hnode).prev;
-                let hnext = self.get_(hnode).next;
-                if hnext == BAD_HANDLE {
-                    self.get_mut_(self.head).prev = hprev;
-                } else {
-                    self.get_mut_(hnext).prev = hprev;
-                }
-                if hnode == self.head {
-                    self.head = hnext;
-                } else {
-                    self.get_mut_(hprev).next = hnext;
-                }
-            } else {
-                self.head = BAD_HANDLE;
-            }
+            let hnode = node.unwrap_or(self.prev_(self.head));
+            self.unlink_(hnode);
             self.len -= 1;
-            let value = self.get_mut_(hnode).value.take();
+            let value = self.get_mut_(hnode).take();
             self.push_recyc(hnode);
             value
         }
     }
 
+    /// Removes the element indicated by `node` as [remove_()](Self::remove_)
+    /// does, then compacts the vector in place if the compaction policy, if
+    /// set, finds it too fragmented.
+    ///
+    fn remove_compacting_(&mut self, node: Option<HNode<I>>) -> Option<T> {
+        let value = self.remove_(node);
+        if value.is_some() {
+            if let Some(mut policy) = self.compaction.take() {
+                let nodes  = self.vec.len();
+                let vacant = self.vacant_count() as f64 / nodes as f64;
+                if policy.should_compact(nodes, self.len, vacant,
+                                         || self.fragmentation()) {
+                    let remap = self.compact_in_place();
+                    policy.compacted(&remap);
+                }
+                self.compaction = Some(policy);
+            }
+        }
+        value
+    }
+
+    /// Detaches the node indicated by `node` from the list without releasing
+    /// it to the recycle list. The length of the list isn't updated. This 
+    /// operation completes in O(1) time.
+    /// 
+    #[inline]
+    fn unlink_(&mut self, hnode: HNode<I>) {
+        if self.len > 1 {
+            let hprev = self.prev_(hnode);
+            let hnext = self.next_(hnode);
+            if hnext == HNode::BAD {
+                self.get_mut_(self.head).prev = hprev.0;
+            } else {
+                self.get_mut_(hnext).prev = hprev.0;
+            }
+            if hnode == self.head {
+                self.head = hnext;
+            } else {
+                self.get_mut_(hprev).next = hnext.0;
+            }
+        } else {
+            self.head = HNode::BAD;
+        }
+    }
+
     /// Returns a reference to the element indicated by the handle, `node`. This
     /// operation completes in O(1) time.
     /// 
     #[inline(always)]
-    pub(crate) fn get_(&self, node: HNode) -> &Node<T> {
-        #[cfg(debug_assertions)]
+    pub(crate) fn get_(&self, node: HNode<I>) -> &Node<T, I> {
+        #[cfg(feature = "generational-handles")]
         self.check_handle(node);
         
-        &self.vec[node.0]
+        &self.vec[node.0.index()]
     }
 
     /// Returns a mutable reference to the element indicated by the handle,
     /// `node`. This operation completes in O(1) time.
     /// 
     #[inline(always)]
-    pub(crate) fn get_mut_(&mut self, node: HNode) -> &mut Node<T> {
-        #[cfg(debug_assertions)]
+    pub(crate) fn get_mut_(&mut self, node: HNode<I>) -> &mut Node<T, I> {
+        #[cfg(feature = "generational-handles")]
         self.check_handle(node);
 
-        &mut self.vec[node.0]
+        &mut self.vec[node.0.index()]
     }
 
-    #[cfg(debug_assertions)]
-    pub(crate) fn check_handle(&self, node: HNode) {
-        assert!(node.0 != BAD_HANDLE.0, "Handle is invalid.");
-        assert!(node.2 == self.uuid, "Handle is not native."); 
-        assert!(node.1 == self.vec[node.0].gen, "Handle has expired.");
+    /// Panics with the reason the handle can't be used with this vector, if
+    /// there is one. Unlike check_handle(), vacant nodes are detected without
+    /// the `"generational-handles"` feature, and the list must not be empty.
+    /// 
+    #[inline]
+    fn assert_handle_(&self, node: HNode<I>) {
+        if let Err(error) = self.validate_handle(node) {
+            panic!("{}", error);
+        }
     }
 
-    /// Renders a new element node and returns a handle to it. This operation
-    /// completes in O(1) time.
+    /// Verifies the handle is usable with this vector. Panics if the handle is
+    /// invalid, foreign to the vector (with `"checked-handles"`), or expired.
+    /// 
+    #[cfg(feature = "generational-handles")]
+    pub(crate) fn check_handle(&self, node: HNode<I>) {
+        assert!(node.0 != HNode::BAD.0, "Handle is invalid.");
+        #[cfg(feature = "checked-handles")]
+        assert!(node.2 == self.id, "Handle is not native."); 
+        assert!(node.1 == self.vec[node.0.index()].gen, "Handle has expired.");
+    }
+
+    /// Creates a new, empty `LinkedVector` with default storage that has room
+    /// for at least `size` nodes.
+    /// 
+    #[inline]
+    pub(crate) fn with_capacity_(size: usize) -> Self 
+    where
+        S: Default,
+    {
+        let mut lv = Self::from_storage_(S::default());
+        lv.vec.reserve_exact(size);
+        lv
+    }
+
+    /// Creates a new, empty `LinkedVector` whose storage is of the same kind
+    /// as that of `self`, and allocates from the same allocator, with room for
+    /// at least `size` nodes.
+    /// 
+    #[inline]
+    fn new_like_(&self, size: usize) -> Self {
+        let mut lv = Self::from_storage_(self.vec.new_like());
+        lv.vec.reserve_exact(size);
+        lv.policy = self.policy;
+        lv
+    }
+
+    /// Creates a new, empty `LinkedVector` that keeps its nodes in `vec`, 
+    /// which must be empty.
+    /// 
+    #[inline]
+    pub(crate) fn from_storage_(vec: S) -> Self {
+        debug_assert!(vec.is_empty());
+        Self { 
+            vec, 
+            recyc  : HNode::BAD, 
+            head   : HNode::BAD, 
+            len    : 0, 
+            policy : RecyclePolicy::Lifo,
+
+            compaction : None,
+            unsorted   : 0,
+
+            #[cfg(feature = "checked-handles")]
+            id     : next_id(),
+
+            #[cfg(feature = "checked-handles")]
+            origin : None,
+
+            #[cfg(feature = "generational-handles")]
+            floor  : I::from_usize(0),
+
+            _marker : PhantomData,
+        }
+    }
+
+    /// Renders a handle to the occupied node at the given index of the
+    /// internal vector.
+    /// 
+    #[inline(always)]
+    pub(crate) fn hnode_(&self, index: usize) -> HNode<I> {
+        #[cfg(feature = "checked-handles")]
+        { HNode(I::from_usize(index), self.vec[index].gen, self.id) }
+
+        #[cfg(all(feature = "generational-handles", 
+                  not(feature = "checked-handles")))]
+        { HNode(I::from_usize(index), self.vec[index].gen) }
+
+        #[cfg(not(feature = "generational-handles"))]
+        { HNode(I::from_usize(index)) }
+    }
+
+    /// Renders a handle from a link held by a node, or `HNode::BAD` if the link
+    /// is `I::NONE`.
+    /// 
+    #[inline(always)]
+    pub(crate) fn hlink_(&self, link: I) -> HNode<I> {
+        if link == I::NONE {
+            HNode::BAD
+        } else {
+            self.hnode_(link.index())
+        }
+    }
+
+    /// Returns a handle to the node following the one indicated by the handle,
+    /// or `HNode::BAD` if it's the last node.
+    /// 
+    #[inline(always)]
+    pub(crate) fn next_(&self, node: HNode<I>) -> HNode<I> {
+        self.hlink_(self.get_(node).next)
+    }
+
+    /// Returns a handle to the node preceding the one indicated by the handle.
+    /// The head node's previous node is the tail.
+    /// 
+    #[inline(always)]
+    pub(crate) fn prev_(&self, node: HNode<I>) -> HNode<I> {
+        self.hlink_(self.get_(node).prev)
+    }
+
+    /// Translates a handle from another vector whose nodes have been moved 
+    /// into this one at the given offset. `HNode::BAD` is left as is.
+    /// 
+    #[inline(always)]
+    fn rebase_(&self, node: HNode<I>, offset: usize) -> HNode<I> {
+        if node == HNode::BAD {
+            HNode::BAD
+        } else {
+            self.hnode_(node.0.index() + offset)
+        }
+    }
+
+    /// Determines whether the handle can be used with this vector without 
+    /// panicking. Unlike `check_handle()`, this also rejects handles to vacant
+    /// nodes, so expired handles are reported even without the 
+    /// `"generational-handles"` feature as long as their node hasn't been 
+    /// recycled.
+    /// 
+    pub(crate) fn validate_handle(&self, node: HNode<I>) 
+        -> Result<(), LinkedVectorError> 
+    {
+        if self.is_empty() {
+            return Err(LinkedVectorError::EmptyList);
+        }
+        if node.0 == HNode::BAD.0 {
+            return Err(LinkedVectorError::InvalidHandle);
+        }
+        #[cfg(feature = "checked-handles")]
+        if node.2 != self.id {
+            return Err(LinkedVectorError::ForeignHandle);
+        }
+        let slot = self.vec.get(node.0.index())
+                           .ok_or(LinkedVectorError::InvalidHandle)?;
+
+        #[cfg(feature = "generational-handles")]
+        if node.1 != slot.gen {
+            return Err(LinkedVectorError::ExpiredHandle);
+        }
+        if !slot.is_occupied() {
+            return Err(LinkedVectorError::ExpiredHandle);
+        }
+        Ok(())
+    }
+
+    /// Renders a new element node and returns a handle to it. Panics if the
+    /// storage is full. `near` is the node the new one will be linked next to,
+    /// if any, which the recycle policy may use to choose a vacant node. See
+    /// [pop_recyc()](Self::pop_recyc) for the time this takes.
     /// 
     #[inline]
-    fn new_node(&mut self, value: T) -> HNode {
-        if let Some(hnode) = self.pop_recyc() {
-            #[cfg(debug_assertions)]
+    fn new_node(&mut self, value: T, near: Option<HNode<I>>) -> HNode<I> {
+        if let Some(hnode) = self.pop_recyc(near) {
+            #[cfg(feature = "generational-handles")]
             {
-                let gen = self.vec[hnode.0].gen;
-                self.vec[hnode.0] = Node::new(value, gen);
+                let gen = self.vec[hnode.0.index()].gen;
+                self.vec[hnode.0.index()] = Node::new(value, hnode.0, gen);
                 let mut hnode = hnode;
                 hnode.1 = gen;
-                hnode 
+                hnode
             }
-            #[cfg(not(debug_assertions))]
+            #[cfg(not(feature = "generational-handles"))]
             { 
-                self.vec[hnode.0] = Node::new(value);
+                self.vec[hnode.0.index()] = Node::new(value, hnode.0);
                 hnode
             }
         } else {
-            #[cfg(debug_assertions)]
-            { 
-                self.vec.push(Node::new(value, 0));
-                HNode(self.vec.len() - 1, 0, self.uuid) 
-            }
-            #[cfg(not(debug_assertions))]
-            { 
-                self.vec.push(Node::new(value));
-                HNode(self.vec.len() - 1) 
-            }
+            let index = I::from_usize(self.vec.len());
+
+            #[cfg(feature = "generational-handles")]
+            let node = Node::new(value, index, self.floor);
+            #[cfg(not(feature = "generational-handles"))]
+            let node = Node::new(value, index);
+
+            self.vec.push(node);
+            self.hnode_(index.index())
         }
     }
 
     /// Internal method that returns a handle to a useable node from the recycle
     /// bin. The node is removed from the bin. Only new_node() should call this.
-    /// Use new_node() if you need a new node instead of this.
+    /// Use new_node() if you need a new node instead of this. The node taken 
+    /// depends on the recycle policy; with `NearNeighbor`, it's the one closest
+    /// to `near`. This operation completes in O(1) time with `Lifo`. The other
+    /// policies first put the nodes released since the last call in order, 
+    /// which takes O(p log p + k) time where p is the number of those nodes 
+    /// and k is the number of vacant nodes, and `NearNeighbor` then searches
+    /// the list in O(k) time.
     /// 
     #[inline]
-    fn pop_recyc(&mut self) -> Option<HNode> {
-        if self.recyc == BAD_HANDLE {
-            None
+    fn pop_recyc(&mut self, near: Option<HNode<I>>) -> Option<HNode<I>> {
+        if self.recyc == HNode::BAD {
+            return None;
+        }
+        if self.policy != RecyclePolicy::Lifo {
+            self.settle_recyc_();
+        }
+        let mut iprev = I::NONE;
+        let mut inode = self.recyc.0;
+
+        if let (RecyclePolicy::NearNeighbor, Some(near)) = (self.policy, near) {
+            // The list is sorted by index, so the closest node is either the
+            // last one before `near` or the first one after it.
+            let target = near.0.index();
+            let mut ibefore = I::NONE;
+            while inode != I::NONE && inode.index() < target {
+                ibefore = iprev;
+                iprev = inode;
+                inode = self.vec[inode.index()].next;
+            }
+            if inode == I::NONE 
+                || (iprev != I::NONE 
+                    && target - iprev.index() < inode.index() - target) {
+                inode = iprev;
+                iprev = ibefore;
+            }
+        }
+        let inext = self.vec[inode.index()].next;
+        if iprev == I::NONE {
+            self.recyc = self.hlink_(inext);
         } else {
-            let hnode = self.recyc;
-            self.recyc = self.vec[hnode.0].next;
-            self.vec[hnode.0].next = BAD_HANDLE;
-            Some(hnode) 
+            self.vec[iprev.index()].next = inext;
         }
+        self.vec[inode.index()].next = I::NONE;
+        Some(self.hnode_(inode.index()))
     }
 
     /// Pushes a recently discarded node, indicated by the handle,  back into 
     /// the recycle bin. This can be called by any method that discards a node.
+    /// The node is pushed onto the front of the bin, and a recycle policy 
+    /// other than `Lifo` puts it in order the next time a node is taken. This
+    /// operation completes in O(1) time.
     /// 
     #[inline]
-    fn push_recyc(&mut self, node: HNode) {
-        self.get_mut_(node).prev = BAD_HANDLE;
-        if self.recyc == BAD_HANDLE {
-            self.vec[node.0].next = BAD_HANDLE;
-            self.recyc = node;
-        } else {
-            self.vec[node.0].next = self.recyc;
-            self.recyc = node;
+    fn push_recyc(&mut self, node: HNode<I>) {
+        self.get_mut_(node).prev = I::NONE;
+        let index = node.0.index();
+
+        self.vec[index].next = self.recyc.0;
+        self.recyc = node;
+
+        if self.policy != RecyclePolicy::Lifo {
+            self.unsorted += 1;
+        }
+        #[cfg(feature = "generational-handles")]
+        {
+            let gen = self.vec[index].gen.next_gen();
+            self.vec[index].gen = gen.max(self.floor);
+        }
+    }
+
+    /// Raises the generation that new nodes at the end of the internal vector
+    /// start at above the generation of every node it holds. This is done 
+    /// before nodes are released from the internal vector. This operation 
+    /// completes in O(n) time where n is the number of nodes in the internal
+    /// vector.
+    /// 
+    #[cfg(feature = "generational-handles")]
+    fn raise_floor_(&mut self) {
+        for i in 0..self.vec.len() {
+            self.floor = self.floor.max(self.vec[i].gen.next_gen());
+        }
+    }
+
+    /// Sorts the unsorted nodes at the front of the recycle list and merges 
+    /// them into the rest of it, which is already in order of index. This 
+    /// operation completes in O(p log p + k) time where p is the number of 
+    /// unsorted nodes and k is the number of vacant nodes.
+    /// 
+    fn settle_recyc_(&mut self) {
+        if self.unsorted == 0 {
+            return;
+        }
+        let mut ilast = self.recyc.0;
+        for _ in 1..self.unsorted {
+            ilast = self.vec[ilast.index()].next;
+        }
+        let isorted = self.vec[ilast.index()].next;
+        self.vec[ilast.index()].next = I::NONE;
+
+        let irun  = self.sort_chain_(self.recyc.0, self.unsorted);
+        let ihead = self.merge_chains_(irun, isorted);
+        self.recyc = self.hlink_(ihead);
+        self.unsorted = 0;
+    }
+
+    /// Sorts the chain of `len` vacant nodes starting at `ihead` by index with
+    /// a merge sort over their `next` links, so nothing is allocated. Returns
+    /// the first node of the sorted chain. This operation completes in 
+    /// O(len log len) time.
+    /// 
+    fn sort_chain_(&mut self, ihead: I, len: usize) -> I {
+        if len < 2 {
+            return ihead;
+        }
+        let mut imid = ihead;
+        for _ in 1..len / 2 {
+            imid = self.vec[imid.index()].next;
+        }
+        let isecond = self.vec[imid.index()].next;
+        self.vec[imid.index()].next = I::NONE;
+
+        let ifirst  = self.sort_chain_(ihead, len / 2);
+        let isecond = self.sort_chain_(isecond, len - len / 2);
+        self.merge_chains_(ifirst, isecond)
+    }
+
+    /// Merges two chains of vacant nodes that are each in order of index into
+    /// one. Returns the first node of the merged chain. This operation 
+    /// completes in O(a + b) time in the worst case, where a and b are the 
+    /// lengths of the chains, but stops once either chain runs out.
+    /// 
+    fn merge_chains_(&mut self, mut ia: I, mut ib: I) -> I {
+        let mut ihead = I::NONE;
+        let mut itail = I::NONE;
+        while ia != I::NONE && ib != I::NONE {
+            let inode;
+            if ia.index() < ib.index() {
+                inode = ia;
+                ia = self.vec[ia.index()].next;
+            } else {
+                inode = ib;
+                ib = self.vec[ib.index()].next;
+            }
+            if itail == I::NONE {
+                ihead = inode;
+            } else {
+                self.vec[itail.index()].next = inode;
+            }
+            itail = inode;
+        }
+        let irest = if ia != I::NONE { ia } else { ib };
+        if itail == I::NONE {
+            return irest;
+        }
+        self.vec[itail.index()].next = irest;
+        ihead
+    }
+
+    /// Removes the elements for which the predicate returns `false` in a 
+    /// single traversal of the list. This operation completes in O(n) time.
+    /// 
+    fn retain_<F>(&mut self, mut pred: F) 
+    where
+        F: FnMut(HNode<I>, &mut T) -> bool,
+    {
+        let mut hnode = self.front_node().unwrap_or(HNode::BAD);
+
+        while hnode != HNode::BAD {
+            let hnext = self.next_(hnode);
+            let value = self.get_mut_(hnode).value_mut().unwrap();
+            if !pred(hnode, value) {
+                self.unlink_(hnode);
+                self.len -= 1;
+                let value = self.get_mut_(hnode).take();
+                self.push_recyc(hnode);
+                drop(value);
+            }
+            hnode = hnext;
         }
-        #[cfg(debug_assertions)]
-        { self.vec[node.0].gen += 1; }
     }
 
     /// Sorts the list by the given comparison function. This operation 
@@ -1103,33 +3359,40 @@ impl<T> LinkedVector<T> {
         let mut handles = self.handles().collect::<Vec<_>>();
         if stable {
             handles.sort_by(|h1, h2| {
-                compare(self.vec[h1.0].value.as_ref().unwrap(), 
-                        self.vec[h2.0].value.as_ref().unwrap())
+                compare(self.vec[h1.0.index()].value().unwrap(), 
+                        self.vec[h2.0.index()].value().unwrap())
             });
         } else {
             handles.sort_unstable_by(|h1, h2| {
-                compare(self.vec[h1.0].value.as_ref().unwrap(), 
-                        self.vec[h2.0].value.as_ref().unwrap())
+                compare(self.vec[h1.0.index()].value().unwrap(), 
+                        self.vec[h2.0.index()].value().unwrap())
             });
         }
         for i in 0..self.len - 1 {
-            self.vec[handles[i].0].next = handles[i + 1];
-            self.vec[handles[i + 1].0].prev = handles[i];
+            self.vec[handles[i].0.index()].next = handles[i + 1].0;
+            self.vec[handles[i + 1].0.index()].prev = handles[i].0;
         }
         let tail = *handles.last().unwrap();
         self.head = handles[0];
-        self.vec[self.head.0].prev = tail;
-        self.vec[tail.0].next = BAD_HANDLE;
+        self.vec[self.head.0.index()].prev = tail.0;
+        self.vec[tail.0.index()].next = I::NONE;
     }
 }
 
-impl<T> Clone for LinkedVector<T> 
+impl<T, I, S> Clone for LinkedVector<T, I, S> 
 where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
     T: Clone,
 {
+    /// Clones the elements into a new, compact vector with its own ID. Handles
+    /// from `self` can't be used with the clone. See 
+    /// [clone_with_layout()](LinkedVector::clone_with_layout) for a clone they
+    /// can be translated for.
+    /// 
     #[inline]
     fn clone(&self) -> Self {
-        let mut lv = Self::new();
+        let mut lv = self.new_like_(self.len);
         for v in self.iter() {
             lv.push_back(v.clone());
         }
@@ -1137,8 +3400,10 @@ where
     }
 }
 
-impl<T> Debug for LinkedVector<T> 
+impl<T, I, S> Debug for LinkedVector<T, I, S> 
 where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
     T: Debug,
 {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -1149,26 +3414,37 @@ where
     }
 }
 
-impl<T> Default for LinkedVector<T> {
+impl<T, I, S> Default for LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>> + Default,
+{
     /// Renders the default value for an HNode. This will internally be set
-    /// to `BAD_HANDLE` which is a handle that is invalid.
+    /// to `HNode::BAD` which is a handle that is invalid.
     /// 
     #[inline]
     fn default() -> Self {
-        Self::new()
+        Self::with_capacity_(0)
     }
 }
 
-impl<T: Eq> Eq for LinkedVector<T> {}
+impl<T, I, S> Eq for LinkedVector<T, I, S> 
+where
+    T: Eq,
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
 
-impl<'a, T> Extend<&'a T> for LinkedVector<T> 
+impl<'a, T, I, S> Extend<&'a T> for LinkedVector<T, I, S> 
 where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
     T: Clone,
 {   
     #[inline]
-    fn extend<I>(&mut self, iter: I)
+    fn extend<It>(&mut self, iter: It)
     where
-        I: IntoIterator<Item = &'a T>,
+        It: IntoIterator<Item = &'a T>,
     {
         for v in iter {
             self.push_back(v.clone());
@@ -1176,11 +3452,15 @@ where
     }
 }
 
-impl<T> Extend<T> for LinkedVector<T> {
+impl<T, I, S> Extend<T> for LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     #[inline]
-    fn extend<I>(&mut self, iter: I)
+    fn extend<It>(&mut self, iter: It)
     where
-        I: IntoIterator<Item = T>,
+        It: IntoIterator<Item = T>,
     {
         for v in iter {
             self.push_back(v);
@@ -1201,9 +3481,9 @@ impl<T, const N: usize> From<[T; N]> for LinkedVector<T> {
 
 impl<T> FromIterator<T> for LinkedVector<T> {
     #[inline]
-    fn from_iter<I>(iter: I) -> Self
+    fn from_iter<It>(iter: It) -> Self
     where
-        I: IntoIterator<Item = T>,
+        It: IntoIterator<Item = T>,
     {
         let mut lv = Self::new();
         for v in iter {
@@ -1213,8 +3493,10 @@ impl<T> FromIterator<T> for LinkedVector<T> {
     }
 }
 
-impl<T> Hash for LinkedVector<T> 
+impl<T, I, S> Hash for LinkedVector<T, I, S> 
 where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
     T: Hash,
 {
     #[inline]
@@ -1225,11 +3507,15 @@ where
     }
 }
 
-impl<T> Index<HNode> for LinkedVector<T> {
+impl<T, I, S> Index<HNode<I>> for LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     type Output = T;
 
     #[inline]
-    fn index(&self, handle: HNode) -> &Self::Output {
+    fn index(&self, handle: HNode<I>) -> &Self::Output {
         #[cfg(feature = "optionless-accessors")]
         { self.get(handle) }
 
@@ -1238,9 +3524,13 @@ impl<T> Index<HNode> for LinkedVector<T> {
     }
 }
 
-impl<T> IndexMut<HNode> for LinkedVector<T> {
+impl<T, I, S> IndexMut<HNode<I>> for LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     #[inline]
-    fn index_mut(&mut self, handle: HNode) -> &mut Self::Output {
+    fn index_mut(&mut self, handle: HNode<I>) -> &mut Self::Output {
         #[cfg(feature = "optionless-accessors")]
         { self.get_mut(handle) }
 
@@ -1249,28 +3539,62 @@ impl<T> IndexMut<HNode> for LinkedVector<T> {
     }
 }
 
-impl<T> Index<usize> for LinkedVector<T> {
+impl<T, I, S> Index<TypedHNode<T, I>> for LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, handle: TypedHNode<T, I>) -> &Self::Output {
+        &self[handle.hnode]
+    }
+}
+
+impl<T, I, S> IndexMut<TypedHNode<T, I>> for LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    #[inline]
+    fn index_mut(&mut self, handle: TypedHNode<T, I>) -> &mut Self::Output {
+        &mut self[handle.hnode]
+    }
+}
+
+impl<T, I, S> Index<usize> for LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     type Output = T;
 
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
         self.handle(index)
-            .and_then(|h| self.vec[h.0].value.as_ref())
+            .and_then(|h| self.vec[h.0.index()].value())
             .expect("Invalid index")
     }
 }
 
-impl<T> IndexMut<usize> for LinkedVector<T> {
+impl<T, I, S> IndexMut<usize> for LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         self.handle(index)
-            .and_then(|h| self.vec[h.0].value.as_mut())
+            .and_then(|h| self.vec[h.0.index()].value_mut())
             .expect("Invalid index")
     }
 }
 
-impl<T> PartialEq for LinkedVector<T> 
-where 
+impl<T, I, S> PartialEq for LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
     T: PartialEq
 {
     #[inline]
@@ -1279,35 +3603,88 @@ where
     }
 }
 
+/// Translates handles that were valid before an operation that relocates 
+/// nodes, such as [compact_in_place()](LinkedVector::compact_in_place), into
+/// handles that refer to the same elements afterwards.
+/// 
+#[derive(Debug, Clone, Default)]
+pub struct HNodeRemap<I = usize> {
+    // Indexed by the old handle's node index. Holds the old handle and its
+    // replacement, or a pair of `HNode::BAD`s if no element was at the index.
+    map : Vec<(HNode<I>, HNode<I>)>,
+}
+
+impl<I: IndexType> HNodeRemap<I> {
+    /// Returns the new handle for the element the old handle referred to, or
+    /// `None` if the old handle wasn't valid when the remap was produced. The
+    /// old handle can be an `HNode` or a `TypedHNode<T>`.
+    /// 
+    #[inline]
+    pub fn get(&self, old: impl Into<HNode<I>>) -> Option<HNode<I>> {
+        let old = old.into();
+        match self.map.get(old.0.index()) {
+            Some(&(hold, hnew)) if hold == old && old != HNode::BAD => {
+                Some(hnew)
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the remap holds no handles.
+    /// 
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Returns an iterator over the `(old, new)` handle pairs of the remap.
+    /// 
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (HNode<I>, HNode<I>)> + '_ {
+        self.map.iter().copied().filter(|&(hold, _)| hold != HNode::BAD)
+    }
+
+    /// Returns the number of handles in the remap.
+    /// 
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+}
+
 /// An iterator over the elements of a `LinkedVector`. Yields the handles of
 /// each element.
 /// 
-pub struct Handles<'a, T> {
-    lv    : &'a LinkedVector<T>,
-    hnode : HNode,
-    hrev  : HNode,
+pub struct Handles<'a, T, I: IndexType = usize, S = Vec<Node<T, I>>> {
+    lv    : &'a LinkedVector<T, I, S>,
+    hnode : HNode<I>,
+    hrev  : HNode<I>,
     len   : usize,
 }
 
-impl<'a, T> Handles<'a, T> {
+impl<'a, T, I: IndexType, S: NodeStorage<Node<T, I>>> Handles<'a, T, I, S> {
     #[inline]
-    pub fn new(lv: &'a LinkedVector<T>) -> Self {
+    pub fn new(lv: &'a LinkedVector<T, I, S>) -> Self {
         Self {
             hnode : lv.head,
-            hrev  : lv.back_node().unwrap_or(BAD_HANDLE),
+            hrev  : lv.back_node().unwrap_or(HNode::BAD),
             len   : lv.len(),
             lv,
         }
     }
 }
 
-impl<'a, T> Iterator for Handles<'a, T> {
-    type Item = HNode;
+impl<'a, T, I, S> Iterator for Handles<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    type Item = HNode<I>;
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.len > 0 {
             let hnode = self.hnode;
-            self.hnode = self.lv.get_(hnode).next;
+            self.hnode = self.lv.next_(hnode);
             self.len -= 1;
             Some(hnode)
         } else {
@@ -1319,18 +3696,22 @@ impl<'a, T> Iterator for Handles<'a, T> {
         (self.len, Some(self.len))
     }
     #[inline]
-    fn last(self) -> Option<Self::Item> {
-        self.lv.front_().map(|h| h.prev)
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
 }
 
-impl<'a, T> DoubleEndedIterator for Handles<'a, T> {
+impl<'a, T, I, S> DoubleEndedIterator for Handles<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.len > 0 {
             let hrev = self.hrev;
             let node = self.lv.get_(hrev);
-            self.hrev = node.prev;
+            self.hrev = self.lv.hlink_(node.prev);
             self.len -= 1;
             Some(hrev)
         } else {
@@ -1339,38 +3720,50 @@ impl<'a, T> DoubleEndedIterator for Handles<'a, T> {
     }
 }
 
-impl<T> ExactSizeIterator for Handles<'_, T> {}
+impl<T, I, S> ExactSizeIterator for Handles<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
 
-impl<T> FusedIterator for Handles<'_, T> {}
+impl<T, I, S> FusedIterator for Handles<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
 
 /// The basic iterator class of `LinkedVector`. Yields references to the 
 /// elements of the vector.
 /// 
-pub struct Iter<'a, T> {
-    lv    : &'a LinkedVector<T>,
-    hnode : HNode,
-    hrev  : HNode,
+pub struct Iter<'a, T, I: IndexType = usize, S = Vec<Node<T, I>>> {
+    lv    : &'a LinkedVector<T, I, S>,
+    hnode : HNode<I>,
+    hrev  : HNode<I>,
     len   : usize,
 }
-impl<'a, T> Iter<'a, T> {
+impl<'a, T, I: IndexType, S: NodeStorage<Node<T, I>>> Iter<'a, T, I, S> {
     #[inline]
-    pub fn new(lv: &'a LinkedVector<T>) -> Self {
+    pub fn new(lv: &'a LinkedVector<T, I, S>) -> Self {
         Self {
             hnode : lv.head,
-            hrev  : lv.back_node().unwrap_or(BAD_HANDLE),
+            hrev  : lv.back_node().unwrap_or(HNode::BAD),
             len   : lv.len(),
             lv,
         }
     }
 }
 
-impl<'a, T> Iterator for Iter<'a, T> {
+impl<'a, T, I, S> Iterator for Iter<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     type Item = &'a T;
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.len > 0 {
             let hnode = self.hnode;
-            self.hnode = self.lv.get_(hnode).next;
+            self.hnode = self.lv.next_(hnode);
             self.len -= 1;
             #[cfg(feature = "optionless-accessors")]
             { Some(self.lv.get(hnode)) }
@@ -1386,18 +3779,22 @@ impl<'a, T> Iterator for Iter<'a, T> {
         (self.len, Some(self.len))
     }
     #[inline]
-    fn last(self) -> Option<Self::Item> {
-        self.lv.back()
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
 }
 
-impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+impl<'a, T, I, S> DoubleEndedIterator for Iter<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.len > 0 {
             let hrev = self.hrev;
             let node = self.lv.get_(hrev);
-            self.hrev = node.prev;
+            self.hrev = self.lv.hlink_(node.prev);
             self.len -= 1;
             #[cfg(feature = "optionless-accessors")]
             { Some(self.lv.get(hrev)) }
@@ -1410,18 +3807,30 @@ impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
     }
 }
 
-impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T, I, S> ExactSizeIterator for Iter<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
 
-impl<T> FusedIterator for Iter<'_, T> {}
+impl<T, I, S> FusedIterator for Iter<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
 
-impl<'a, T> IntoIterator for &'a LinkedVector<T> {
+impl<'a, T, I, S> IntoIterator for &'a LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     type Item = &'a T;
-    type IntoIter = Iter<'a, T>;
+    type IntoIter = Iter<'a, T, I, S>;
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
         Iter {
             hnode : self.head,
-            hrev  : self.back_node().unwrap_or(BAD_HANDLE),
+            hrev  : self.back_node().unwrap_or(HNode::BAD),
             len   : self.len(),
             lv    : self,
         }
@@ -1431,32 +3840,36 @@ impl<'a, T> IntoIterator for &'a LinkedVector<T> {
 /// The basic iterator class of `LinkedVector`. Yields mutable references to
 /// the elements of the vector.
 /// 
-pub struct IterMut<'a, T> {
-    lv    : &'a mut LinkedVector<T>,
-    hnode : HNode,
-    hrev  : HNode,
+pub struct IterMut<'a, T, I: IndexType = usize, S = Vec<Node<T, I>>> {
+    lv    : &'a mut LinkedVector<T, I, S>,
+    hnode : HNode<I>,
+    hrev  : HNode<I>,
     len   : usize,
 }
 
-impl<'a, T> IterMut<'a, T> {
+impl<'a, T, I: IndexType, S: NodeStorage<Node<T, I>>> IterMut<'a, T, I, S> {
     #[inline]
-    pub fn new(lv: &'a mut LinkedVector<T>) -> Self {
+    pub fn new(lv: &'a mut LinkedVector<T, I, S>) -> Self {
         Self {
             hnode : lv.head,
-            hrev  : lv.back_node().unwrap_or(BAD_HANDLE),
+            hrev  : lv.back_node().unwrap_or(HNode::BAD),
             len   : lv.len(),
             lv,
         }
     }
 }
 
-impl<'a, T> Iterator for IterMut<'a, T> {
+impl<'a, T, I, S> Iterator for IterMut<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     type Item = &'a mut T;
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.len > 0 {
             let hnode = self.hnode;
-            self.hnode = self.lv.get_(hnode).next;
+            self.hnode = self.lv.next_(hnode);
             self.len -= 1;
             #[cfg(feature = "optionless-accessors")]
             unsafe { Some(&mut *(self.lv.get_mut(hnode) as *mut T))}
@@ -1472,18 +3885,22 @@ impl<'a, T> Iterator for IterMut<'a, T> {
         (self.len, Some(self.len))
     }
     #[inline]
-    fn last(self) -> Option<Self::Item> {
-        self.lv.back_mut()
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
 }
 
-impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+impl<'a, T, I, S> DoubleEndedIterator for IterMut<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.len > 0 {
             let hrev = self.hrev;
             let node = self.lv.get_(hrev);
-            self.hrev = node.prev;
+            self.hrev = self.lv.hlink_(node.prev);
             self.len -= 1;
             #[cfg(feature = "optionless-accessors")]
             unsafe { Some(&mut *(self.lv.get_mut(hrev) as *mut T))}
@@ -1496,18 +3913,30 @@ impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
     }
 }
 
-impl<T> ExactSizeIterator for IterMut<'_, T> {}
+impl<T, I, S> ExactSizeIterator for IterMut<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
 
-impl<T> FusedIterator for IterMut<'_, T> {}
+impl<T, I, S> FusedIterator for IterMut<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
 
-impl<'a, T> IntoIterator for &'a mut LinkedVector<T> {
+impl<'a, T, I, S> IntoIterator for &'a mut LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     type Item = &'a mut T;
-    type IntoIter = IterMut<'a, T>;
+    type IntoIter = IterMut<'a, T, I, S>;
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
         IterMut {
             hnode : self.head,
-            hrev  : self.back_node().unwrap_or(BAD_HANDLE),
+            hrev  : self.back_node().unwrap_or(HNode::BAD),
             len   : self.len(),
             lv    : self,
         }
@@ -1517,22 +3946,219 @@ impl<'a, T> IntoIterator for &'a mut LinkedVector<T> {
 /// The consuming iterator class of `LinkedVector`. Yields owned elements of the
 /// vector.
 /// 
-pub struct IntoIter<T>(LinkedVector<T>);
+pub struct IntoIter<T, I: IndexType = usize, S = Vec<Node<T, I>>>(
+    LinkedVector<T, I, S>);
 
-impl<T> IntoIterator for LinkedVector<T> {
+impl<T, I, S> IntoIterator for LinkedVector<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, I, S>;
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
         IntoIter(self)
     }
 }
 
-impl<T> Iterator for IntoIter<T> {
+impl<T, I, S> Iterator for IntoIter<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     type Item = T;
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.pop_front()
+        let hnode = self.0.front_node()?;
+        self.0.remove_(Some(hnode))
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<T, I, S> DoubleEndedIterator for IntoIter<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.remove_(None)
+    }
+}
+
+impl<T, I, S> ExactSizeIterator for IntoIter<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
+
+impl<T, I, S> FusedIterator for IntoIter<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
+
+/// An iterator over the elements of a `LinkedVector` that yields the handle of
+/// each element along with a reference to it.
+/// 
+pub struct IterWithHandles<'a, T, I: IndexType = usize, S = Vec<Node<T, I>>> {
+    lv    : &'a LinkedVector<T, I, S>,
+    hnode : HNode<I>,
+    hrev  : HNode<I>,
+    len   : usize,
+}
+
+impl<'a, T, I, S> Iterator for IterWithHandles<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    type Item = (HNode<I>, &'a T);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len > 0 {
+            let hnode = self.hnode;
+            let node  = self.lv.get_(hnode);
+            self.hnode = self.lv.hlink_(node.next);
+            self.len -= 1;
+            node.value().map(|v| (hnode, v))
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T, I, S> DoubleEndedIterator for IterWithHandles<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len > 0 {
+            let hrev = self.hrev;
+            let node = self.lv.get_(hrev);
+            self.hrev = self.lv.hlink_(node.prev);
+            self.len -= 1;
+            node.value().map(|v| (hrev, v))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, I, S> ExactSizeIterator for IterWithHandles<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
+
+impl<T, I, S> FusedIterator for IterWithHandles<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
+
+/// An iterator over the elements of a `LinkedVector` that yields the handle of
+/// each element along with a mutable reference to it.
+/// 
+pub struct IterMutWithHandles<'a, T, I = usize, S = Vec<Node<T, I>>> 
+where
+    I: IndexType,
+{
+    lv    : &'a mut LinkedVector<T, I, S>,
+    hnode : HNode<I>,
+    hrev  : HNode<I>,
+    len   : usize,
+}
+
+impl<'a, T, I, S> Iterator for IterMutWithHandles<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    type Item = (HNode<I>, &'a mut T);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len > 0 {
+            let hnode = self.hnode;
+            self.hnode = self.lv.next_(hnode);
+            self.len -= 1;
+            let value = self.lv.get_mut_(hnode).value_mut().unwrap();
+            unsafe { Some((hnode, &mut *(value as *mut T))) }
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T, I, S> DoubleEndedIterator for IterMutWithHandles<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len > 0 {
+            let hrev = self.hrev;
+            self.hrev = self.lv.prev_(hrev);
+            self.len -= 1;
+            let value = self.lv.get_mut_(hrev).value_mut().unwrap();
+            unsafe { Some((hrev, &mut *(value as *mut T))) }
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, I, S> ExactSizeIterator for IterMutWithHandles<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
+
+impl<T, I, S> FusedIterator for IterMutWithHandles<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
+
+/// The consuming iterator of `LinkedVector` that yields the owned elements of 
+/// the vector along with the handles they had in it.
+/// 
+pub struct IntoIterWithHandles<T, I: IndexType = usize, S = Vec<Node<T, I>>>(
+    LinkedVector<T, I, S>);
+
+impl<T, I, S> Iterator for IntoIterWithHandles<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    type Item = (HNode<I>, T);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let hnode = self.0.front_node()?;
+        self.0.remove_(Some(hnode)).map(|v| (hnode, v))
     }
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -1540,13 +4166,147 @@ impl<T> Iterator for IntoIter<T> {
     }
 }
 
-impl<T> DoubleEndedIterator for IntoIter<T> {
+impl<T, I, S> DoubleEndedIterator for IntoIterWithHandles<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let hnode = self.0.back_node()?;
+        self.0.remove_(Some(hnode)).map(|v| (hnode, v))
+    }
+}
+
+impl<T, I, S> ExactSizeIterator for IntoIterWithHandles<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
+
+impl<T, I, S> FusedIterator for IntoIterWithHandles<T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
+
+/// A draining iterator over a range of elements of a `LinkedVector`. Created
+/// by [drain()](LinkedVector::drain). Yields the owned elements, unlinking 
+/// them from the vector as it advances. Any elements of the range left when
+/// the iterator is dropped are removed.
+/// 
+pub struct Drain<'a, T, I = usize, S = Vec<Node<T, I>>> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    lv    : &'a mut LinkedVector<T, I, S>,
+    hnode : HNode<I>,
+    hrev  : HNode<I>,
+    len   : usize,
+}
+
+impl<'a, T, I, S> Iterator for Drain<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
+    type Item = T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len > 0 {
+            let hnode = self.hnode;
+            self.hnode = self.lv.next_(hnode);
+            self.len -= 1;
+            self.lv.remove_(Some(hnode))
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T, I, S> DoubleEndedIterator for Drain<'a, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.0.pop_back()
+        if self.len > 0 {
+            let hrev = self.hrev;
+            self.hrev = self.lv.prev_(hrev);
+            self.len -= 1;
+            self.lv.remove_(Some(hrev))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, I, S> ExactSizeIterator for Drain<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
+
+impl<T, I, S> FusedIterator for Drain<'_, T, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+{}
+
+impl<T, I: IndexType, S: NodeStorage<Node<T, I>>> Drop for Drain<'_, T, I, S> {
+    fn drop(&mut self) {
+        self.for_each(drop);
     }
 }
 
-impl<T> ExactSizeIterator for IntoIter<T> {}
+/// An iterator that removes the elements of a `LinkedVector` that match a 
+/// predicate. Created by [extract_if()](LinkedVector::extract_if). Elements 
+/// that haven't been visited when the iterator is dropped remain in the 
+/// vector.
+/// 
+pub struct ExtractIf<'a, T, F, I: IndexType = usize, S = Vec<Node<T, I>>> 
+where
+    F: FnMut(&mut T) -> bool,
+{
+    lv    : &'a mut LinkedVector<T, I, S>,
+    hnode : HNode<I>,
+    pred  : F,
+}
+
+impl<'a, T, F, I, S> Iterator for ExtractIf<'a, T, F, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.hnode != HNode::BAD {
+            let hnode = self.hnode;
+            self.hnode = self.lv.next_(hnode);
+            let value = self.lv.get_mut_(hnode).value_mut().unwrap();
+            if (self.pred)(value) {
+                return self.lv.remove_(Some(hnode));
+            }
+        }
+        None
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.lv.len()))
+    }
+}
 
-impl<T> FusedIterator for IntoIter<T> {}
+impl<T, F, I, S> FusedIterator for ExtractIf<'_, T, F, I, S> 
+where
+    I: IndexType,
+    S: NodeStorage<Node<T, I>>,
+    F: FnMut(&mut T) -> bool,
+{}
@@ -0,0 +1,52 @@
+/// The policy a `LinkedVector` follows to choose which vacant node to reuse
+/// when an element is added. Nodes of removed elements wait in a recycle list
+/// until they're reused, and the policy decides where in the internal vector
+/// new elements land, which affects how well iteration uses the cache after
+/// elements have been removed and added many times. Set the policy with
+/// [set_recycle_policy()](crate::LinkedVector::set_recycle_policy).
+///
+/// The recycle list is threaded through the vacant nodes themselves, so none
+/// of the policies allocate. Removing an element takes O(1) time under every
+/// policy, as its node is pushed onto the front of the list. The policies 
+/// other than `Lifo` need the list in order of index, so the next element 
+/// added first sorts the nodes released since the last one was added and 
+/// merges them into the list. With p such nodes and k vacant nodes in all, 
+/// that takes O(p log p + k) time, so removing many elements before adding 
+/// any costs O(log p) per removal rather than a search of the list each.
+/// ```
+/// use linked_vector::*;
+/// let mut lv = LinkedVector::from([1, 2, 3, 4]);
+/// lv.set_recycle_policy(RecyclePolicy::LowestIndex);
+///
+/// let h1 = lv.handle(0).unwrap();
+/// let h3 = lv.handle(2).unwrap();
+///
+/// lv.remove(h1);
+/// lv.remove(h3);
+///
+/// // The low 32 bits of a raw handle are the index of its node.
+/// assert_eq!(lv.push_back(5).into_raw() as u32, 0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecyclePolicy {
+    /// The most recently vacated node is reused first. Adding and removing
+    /// elements take O(1) time, and the list is never sorted. This is the 
+    /// default policy.
+    #[default]
+    Lifo,
+
+    /// The vacant node with the lowest index is reused first, which keeps the
+    /// elements packed toward the front of the internal vector. Removing an
+    /// element takes O(1) time. Adding one takes O(1) time, or 
+    /// O(p log p + k) time when p nodes have been vacated since the last 
+    /// element was added and have to be put in order first.
+    LowestIndex,
+
+    /// The vacant node closest in the internal vector to the node the new
+    /// element is linked next to is reused, so elements that are neighbors in
+    /// the list tend to be neighbors in memory. Removing an element takes O(1)
+    /// time. Adding one takes O(k) time in the worst case, as the recycle 
+    /// list is searched for the node, plus O(p log p) time when p nodes have
+    /// been vacated since the last element was added.
+    NearNeighbor,
+}
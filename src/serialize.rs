@@ -0,0 +1,480 @@
+use core::fmt::{self, Formatter};
+use core::marker::PhantomData;
+use core::mem::size_of;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::index_type::*;
+use crate::linked_vector::*;
+use crate::storage::*;
+
+// Upper bound, in bytes, on the capacity reserved up front from a sequence's
+// size hint. The hint comes from the serialized data, so it can't be trusted
+// to size an allocation on its own.
+const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+
+impl<T, I, St> Serialize for LinkedVector<T, I, St>
+where
+    I: IndexType,
+    St: NodeStorage<Node<T, I>>,
+    T: Serialize,
+{
+    /// Serializes the elements of the vector as a sequence in logical order,
+    /// from front to back. Handles aren't preserved.
+    ///
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self)
+    }
+}
+
+impl<'de, T, I, St> Deserialize<'de> for LinkedVector<T, I, St>
+where
+    I: IndexType,
+    St: NodeStorage<Node<T, I>> + Default,
+    T: Deserialize<'de>,
+{
+    /// Deserializes a sequence of elements into a new vector, pushing each to
+    /// the back in the order they're read. Returns an error if the storage of
+    /// the vector can't hold all the elements.
+    ///
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeqVisitor(PhantomData))
+    }
+}
+
+impl<I: IndexType> Serialize for HNode<I> {
+    /// Serializes the handle as a tuple of its index, generation, and the ID
+    /// of the vector it belongs to. The generation is written as `0` and the
+    /// ID as `None` when the features that add them to handles are disabled, 
+    /// so the format is the same for every feature set.
+    ///
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let index = (self.0 != I::NONE).then_some(self.0.index());
+
+        #[cfg(feature = "checked-handles")]
+        { (index, self.1.index(), Some(self.2)).serialize(serializer) }
+
+        #[cfg(all(feature = "generational-handles", 
+                  not(feature = "checked-handles")))]
+        { (index, self.1.index(), None::<usize>).serialize(serializer) }
+
+        #[cfg(not(feature = "generational-handles"))]
+        { (index, 0usize, None::<usize>).serialize(serializer) }
+    }
+}
+
+impl<'de, I: IndexType> Deserialize<'de> for HNode<I> {
+    /// Deserializes a handle written by the `Serialize` implementation. A 
+    /// handle is only valid with the vector it was issued by, so the vector
+    /// should be restored with [layout] for the handle to be of use. Returns an
+    /// error if the index or the generation doesn't fit the index type.
+    ///
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (index, gen, id) = 
+            <(Option<usize>, usize, Option<usize>)>::deserialize(
+                deserializer)?;
+
+        let Some(index) = index else {
+            return Ok(HNode::BAD);
+        };
+        if index >= I::NONE.index() {
+            return Err(de::Error::custom(
+                "handle index exceeds the capacity of the index type"));
+        }
+        let index = I::from_usize(index);
+
+        #[cfg(feature = "generational-handles")]
+        let gen = I::gen_from_usize(gen).ok_or_else(|| de::Error::custom(
+            "handle generation exceeds the capacity of the index type"))?;
+
+        #[cfg(feature = "checked-handles")]
+        { Ok(HNode(index, gen, id.unwrap_or(usize::MAX))) }
+
+        #[cfg(all(feature = "generational-handles", 
+                  not(feature = "checked-handles")))]
+        { let _ = id; Ok(HNode(index, gen)) }
+
+        #[cfg(not(feature = "generational-handles"))]
+        { let _ = (gen, id); Ok(HNode(index)) }
+    }
+}
+
+impl<T, I: IndexType> Serialize for TypedHNode<T, I> {
+    /// Serializes the handle the same way as `HNode`.
+    ///
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        HNode::<I>::from(*self).serialize(serializer)
+    }
+}
+
+impl<'de, T, I: IndexType> Deserialize<'de> for TypedHNode<T, I> {
+    /// Deserializes a handle written as an `HNode` or `TypedHNode`.
+    ///
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        HNode::<I>::deserialize(deserializer).map(TypedHNode::new)
+    }
+}
+
+struct SeqVisitor<T, I, St>(PhantomData<(T, I, St)>);
+
+impl<'de, T, I, St> Visitor<'de> for SeqVisitor<T, I, St>
+where
+    I: IndexType,
+    St: NodeStorage<Node<T, I>> + Default,
+    T: Deserialize<'de>,
+{
+    type Value = LinkedVector<T, I, St>;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let cap = seq.size_hint().unwrap_or(0)
+                     .min(MAX_PREALLOC_BYTES / size_of::<T>().max(1));
+        let mut lv = LinkedVector::<T, I, St>::with_capacity_(0);
+
+        // The size hint is only used to preallocate, so storage that can't 
+        // hold that many nodes is left to fail on the element that overflows.
+        let _ = lv.vec.try_reserve_exact(cap);
+
+        while let Some(value) = seq.next_element()? {
+            if lv.try_push_back(value).is_err() {
+                return Err(de::Error::custom(
+                    "too many elements for the storage of the vector"));
+            }
+        }
+        Ok(lv)
+    }
+}
+
+/// Serialization of a `LinkedVector` that preserves the layout of its internal
+/// vector. Every node is written, including vacant ones, along with its links
+/// and generation, and the head, the recycle list, the vector's ID, and the
+/// generation new nodes start at are kept as well. A vector restored this way
+/// accepts the handles issued by the one that was serialized, so handles saved
+/// elsewhere remain valid.
+///
+/// The module is meant to be used with serde's `with` attribute:
+/// ```
+/// use linked_vector::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Snapshot {
+///     #[serde(with = "linked_vector::layout")]
+///     list   : LinkedVector<i32>,
+///     marker : HNode,
+/// }
+/// let mut list = LinkedVector::from([1, 2, 3]);
+/// let marker   = list.handle(1).unwrap();
+/// list.pop_front();
+///
+/// let json = serde_json::to_string(&Snapshot { list, marker }).unwrap();
+/// let snap = serde_json::from_str::<Snapshot>(&json).unwrap();
+///
+/// assert_eq!(snap.list[snap.marker], 2);
+/// ```
+/// When deserializing, the link structure is validated, and corrupted data is
+/// rejected with an error rather than producing a broken vector.
+///
+/// With `"checked-handles"`, IDs are counted per process, so a restored ID 
+/// may have already been given to a vector created by this process. Vectors 
+/// created after the restored one are given IDs above it, so restore vectors
+/// before creating others whose handles they mustn't accept.
+///
+pub mod layout {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::mem::MaybeUninit;
+
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::index_type::*;
+    use crate::linked_vector::*;
+    use crate::storage::*;
+
+    // The value, next link, previous link, and generation of a node. Vacant
+    // nodes have no value or previous link; their next link is the recycle 
+    // list.
+    type RawNode<T> = (Option<T>, Option<usize>, Option<usize>, usize);
+
+    #[derive(Serialize)]
+    #[serde(rename = "LinkedVector", 
+            bound = "T: Serialize, St: NodeStorage<Node<T, I>>")]
+    struct LayoutRef<'a, T, I: IndexType, St> {
+        nodes : Nodes<'a, T, I, St>,
+        head  : Option<usize>,
+        recyc : Option<usize>,
+        len   : usize,
+        id    : Option<usize>,
+        floor : usize,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "LinkedVector")]
+    struct Layout<T> {
+        nodes : Vec<RawNode<T>>,
+        head  : Option<usize>,
+        recyc : Option<usize>,
+        len   : usize,
+        id    : Option<usize>,
+
+        // Absent from layouts written before it was added.
+        #[serde(default)]
+        floor : usize,
+    }
+
+    struct Nodes<'a, T, I: IndexType, St>(&'a LinkedVector<T, I, St>);
+
+    impl<T, I, St> Serialize for Nodes<'_, T, I, St>
+    where
+        I: IndexType,
+        St: NodeStorage<Node<T, I>>,
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let nodes = &self.0.vec;
+
+            serializer.collect_seq((0..nodes.len()).map(|i| {
+                let node = &nodes[i];
+
+                #[cfg(feature = "generational-handles")]
+                let gen = node.gen.index();
+                #[cfg(not(feature = "generational-handles"))]
+                let gen = 0;
+
+                let next = index(node.next);
+                let prev = index(node.prev);
+
+                (node.value(), next, prev, gen)
+            }))
+        }
+    }
+
+    /// Serializes the vector with the layout of its internal vector intact.
+    ///
+    pub fn serialize<T, I, St, S>(lv: &LinkedVector<T, I, St>, serializer: S) 
+        -> Result<S::Ok, S::Error>
+    where
+        I: IndexType,
+        St: NodeStorage<Node<T, I>>,
+        T: Serialize,
+        S: Serializer,
+    {
+        #[cfg(feature = "checked-handles")]
+        let id = Some(lv.id);
+        #[cfg(not(feature = "checked-handles"))]
+        let id = None;
+
+        #[cfg(feature = "generational-handles")]
+        let floor = lv.floor.index();
+        #[cfg(not(feature = "generational-handles"))]
+        let floor = 0;
+
+        LayoutRef {
+            nodes : Nodes(lv),
+            head  : index(lv.head.0),
+            recyc : index(lv.recyc.0),
+            len   : lv.len,
+            id,
+            floor,
+        }.serialize(serializer)
+    }
+
+    /// Deserializes a vector written by [serialize()], restoring the layout of
+    /// its internal vector. Returns an error if the links between the nodes 
+    /// don't form a valid list and recycle list, or if there are more nodes
+    /// than the index type can refer to or the storage can hold.
+    ///
+    pub fn deserialize<'de, T, I, St, D>(deserializer: D) 
+        -> Result<LinkedVector<T, I, St>, D::Error>
+    where
+        I: IndexType,
+        St: NodeStorage<Node<T, I>> + Default,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let layout = Layout::deserialize(deserializer)?;
+
+        validate::<T, I>(&layout).map_err(|msg| {
+            de::Error::custom(format_args!("Invalid layout: {}", msg))
+        })?;
+
+        let mut lv = LinkedVector::<T, I, St>::with_capacity_(0);
+
+        if lv.vec.try_reserve_exact(layout.nodes.len()).is_err() {
+            return Err(de::Error::custom(
+                "Invalid layout: too many nodes for the storage"));
+        }
+
+        #[cfg(feature = "checked-handles")]
+        if let Some(id) = layout.id {
+            reserve_id(id);
+            lv.id = id;
+        }
+        #[cfg(not(feature = "checked-handles"))]
+        let _ = layout.id;
+
+        // Validation guarantees that the generations fit the index type.
+        #[cfg(feature = "generational-handles")]
+        { lv.floor = I::gen_from_usize(layout.floor).unwrap(); }
+        #[cfg(not(feature = "generational-handles"))]
+        let _ = layout.floor;
+
+        for (value, next, prev, gen) in layout.nodes {
+            #[cfg(not(feature = "generational-handles"))]
+            let _ = gen;
+
+            // Validation guarantees that exactly the nodes with a value have a
+            // previous link, which is what marks a node as occupied.
+            let node = Node { 
+                value : value.map_or(MaybeUninit::uninit(), MaybeUninit::new), 
+                next  : link(next), 
+                prev  : link(prev),
+
+                #[cfg(feature = "generational-handles")]
+                gen   : I::gen_from_usize(gen).unwrap(),
+            };
+            lv.vec.push(node);
+        }
+        lv.head  = layout.head.map_or(HNode::BAD, |i| lv.hnode_(i));
+        lv.recyc = layout.recyc.map_or(HNode::BAD, |i| lv.hnode_(i));
+        lv.len   = layout.len;
+        Ok(lv)
+    }
+
+    /// Converts a link into the index it refers to, or `None` if it doesn't
+    /// refer to a node.
+    ///
+    #[inline]
+    fn index<I: IndexType>(link: I) -> Option<usize> {
+        (link != I::NONE).then_some(link.index())
+    }
+
+    /// Converts an index read from a layout into a link. The index must have
+    /// been validated to fit the index type.
+    ///
+    #[inline]
+    fn link<I: IndexType>(index: Option<usize>) -> I {
+        index.map_or(I::NONE, I::from_usize)
+    }
+
+    /// Verifies the links of the deserialized nodes. The list must be a 
+    /// doubly-linked cycle of `len` occupied nodes through `prev`, and a chain
+    /// through `next` ending at the tail. Every other node must be vacant and
+    /// on the recycle list. No node may be reachable twice, and the nodes must
+    /// fit the index type `I`.
+    ///
+    fn validate<T, I>(layout: &Layout<T>) -> Result<(), &'static str> 
+    where
+        I: IndexType,
+    {
+        let nodes    = &layout.nodes;
+        let n        = nodes.len();
+        let mut seen = vec![false; n];
+
+        if n > I::NONE.index() {
+            return Err("too many nodes for the index type");
+        }
+        if layout.len > n {
+            return Err("length exceeds the number of nodes");
+        }
+        if I::gen_from_usize(layout.floor).is_none() 
+            || nodes.iter().any(|node| I::gen_from_usize(node.3).is_none()) {
+            return Err("generation exceeds the index type");
+        }
+        match layout.head {
+            None if layout.len != 0 => {
+                return Err("non-empty list without a head");
+            },
+            None => {},
+            Some(_) if layout.len == 0 => {
+                return Err("empty list with a head");
+            },
+            Some(head) => {
+                let mut cur = head;
+                for i in 0..layout.len {
+                    if cur >= n {
+                        return Err("link out of bounds");
+                    }
+                    if seen[cur] {
+                        return Err("node linked more than once");
+                    }
+                    seen[cur] = true;
+                    let (value, next, _, _) = &nodes[cur];
+                    if value.is_none() {
+                        return Err("vacant node in list");
+                    }
+                    if i + 1 == layout.len {
+                        if next.is_some() {
+                            return Err("tail links to another node");
+                        }
+                        break;
+                    }
+                    let next = next.ok_or("list is shorter than its length")?;
+                    if next >= n {
+                        return Err("link out of bounds");
+                    }
+                    if nodes[next].2 != Some(cur) {
+                        return Err("mismatched previous link");
+                    }
+                    cur = next;
+                }
+                if head >= n || nodes[head].2 != Some(cur) {
+                    return Err("head doesn't link back to the tail");
+                }
+            },
+        }
+        let mut vacant = 0;
+        let mut cur    = layout.recyc;
+        while let Some(i) = cur {
+            if i >= n {
+                return Err("link out of bounds");
+            }
+            if seen[i] {
+                return Err("node linked more than once");
+            }
+            seen[i] = true;
+            let (value, next, prev, _) = &nodes[i];
+            if value.is_some() || prev.is_some() {
+                return Err("occupied node in recycle list");
+            }
+            vacant += 1;
+            cur = *next;
+        }
+        if layout.len + vacant != n {
+            return Err("nodes unreachable from the list or recycle list");
+        }
+        Ok(())
+    }
+}
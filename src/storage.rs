@@ -0,0 +1,575 @@
+use alloc::collections::TryReserveError;
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+use core::ops::{Index, IndexMut};
+use core::ptr;
+use core::slice;
+
+#[cfg(feature = "allocator-api2")]
+use allocator_api2::alloc::Allocator;
+
+/// The storage that holds the nodes of a `LinkedVector`. Nodes are addressed
+/// by their position, and are only ever added at the end. `Vec` is the default
+/// storage; [ArrayStorage] holds a fixed number of nodes inline, without any
+/// heap allocation; and [PagedStorage] keeps them in fixed-size pages, so they
+/// never move as the vector grows. With the `"allocator-api2"` feature, the 
+/// `Vec` of that crate stores the nodes in memory from a custom allocator.
+///
+/// Other backends can be plugged in by implementing this trait. The nodes 
+/// occupy positions `0..len()`, and indexing any of them must give the node 
+/// that was last pushed or swapped into that position. The vector keeps its 
+/// links and handles as positions, so storage must never reorder nodes on its
+/// own. Storage that can't hold another node should panic in `push()` and 
+/// `reserve()`, and return an error from `try_reserve()`.
+///
+pub trait NodeStorage<N>: Index<usize, Output = N> + IndexMut<usize> {
+    /// Creates empty storage of the same kind, which allocates from the same
+    /// allocator, if it has one.
+    ///
+    fn new_like(&self) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the number of nodes in the storage.
+    ///
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the storage holds no nodes.
+    ///
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of nodes the storage can hold without growing.
+    ///
+    fn capacity(&self) -> usize;
+
+    /// Returns a reference to the node at `index`, or `None` if it's out of
+    /// bounds.
+    ///
+    fn get(&self, index: usize) -> Option<&N>;
+
+    /// Returns a mutable reference to the node at `index`, or `None` if it's
+    /// out of bounds.
+    ///
+    fn get_mut(&mut self, index: usize) -> Option<&mut N>;
+
+    /// Adds a node to the end of the storage. Panics if the storage is full
+    /// and can't grow.
+    ///
+    fn push(&mut self, node: N);
+
+    /// Makes room for at least `additional` more nodes. Panics if the storage
+    /// can't hold them.
+    ///
+    fn reserve(&mut self, additional: usize);
+
+    /// Makes room for exactly `additional` more nodes, without speculatively
+    /// over-allocating. Panics if the storage can't hold them.
+    ///
+    fn reserve_exact(&mut self, additional: usize);
+
+    /// Makes room for at least `additional` more nodes, or returns an error if
+    /// the storage can't hold them, either because allocation failed or
+    /// because it can't grow.
+    ///
+    fn try_reserve(&mut self, additional: usize) 
+        -> Result<(), TryReserveError>;
+
+    /// Makes room for exactly `additional` more nodes, or returns an error if
+    /// the storage can't hold them.
+    ///
+    fn try_reserve_exact(&mut self, additional: usize) 
+        -> Result<(), TryReserveError>;
+
+    /// Swaps the nodes at the two positions.
+    ///
+    fn swap(&mut self, a: usize, b: usize);
+
+    /// Drops the nodes from position `len` onward.
+    ///
+    fn truncate(&mut self, len: usize);
+
+    /// Drops all the nodes in the storage.
+    ///
+    #[inline]
+    fn clear(&mut self) {
+        self.truncate(0);
+    }
+}
+
+impl<N> NodeStorage<N> for Vec<N> {
+    #[inline]
+    fn new_like(&self) -> Self {
+        Vec::new()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<&N> {
+        self.as_slice().get(index)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut N> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    #[inline]
+    fn push(&mut self, node: N) {
+        self.push(node);
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    #[inline]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional);
+    }
+
+    #[inline]
+    fn try_reserve(&mut self, additional: usize) 
+        -> Result<(), TryReserveError> 
+    {
+        self.try_reserve(additional)
+    }
+
+    #[inline]
+    fn try_reserve_exact(&mut self, additional: usize) 
+        -> Result<(), TryReserveError> 
+    {
+        self.try_reserve_exact(additional)
+    }
+
+    #[inline]
+    fn swap(&mut self, a: usize, b: usize) {
+        self.as_mut_slice().swap(a, b);
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+}
+
+/// Storage for at most `CAP` nodes held inline in an array. It never
+/// allocates, so a `LinkedVector` that uses it, an [ArrayLinkedVector], can
+/// be used where heap allocation isn't allowed. Adding a node to full storage
+/// fails rather than growing it. The failure is reported as a 
+/// `TryReserveError` of the capacity overflow kind, since `TryReserveError` 
+/// has no kind for full storage and can't be constructed with one.
+///
+/// [ArrayLinkedVector]: crate::ArrayLinkedVector
+///
+pub struct ArrayStorage<N, const CAP: usize> {
+    nodes : [MaybeUninit<N>; CAP],
+    len   : usize,
+}
+
+impl<N, const CAP: usize> ArrayStorage<N, CAP> {
+    /// Creates empty storage.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            // SAFETY: An array of `MaybeUninit` needs no initialization.
+            nodes : unsafe { MaybeUninit::uninit().assume_init() },
+            len   : 0,
+        }
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[N] {
+        // SAFETY: The first `len` nodes are initialized.
+        unsafe {
+            slice::from_raw_parts(self.nodes.as_ptr() as *const N, self.len)
+        }
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [N] {
+        // SAFETY: The first `len` nodes are initialized.
+        unsafe {
+            slice::from_raw_parts_mut(self.nodes.as_mut_ptr() as *mut N,
+                                      self.len)
+        }
+    }
+}
+
+impl<N, const CAP: usize> Default for ArrayStorage<N, CAP> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, const CAP: usize> Drop for ArrayStorage<N, CAP> {
+    #[inline]
+    fn drop(&mut self) {
+        self.truncate(0);
+    }
+}
+
+impl<N, const CAP: usize> Index<usize> for ArrayStorage<N, CAP> {
+    type Output = N;
+
+    #[inline]
+    fn index(&self, index: usize) -> &N {
+        &self.as_slice()[index]
+    }
+}
+
+impl<N, const CAP: usize> IndexMut<usize> for ArrayStorage<N, CAP> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut N {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+impl<N, const CAP: usize> NodeStorage<N> for ArrayStorage<N, CAP> {
+    #[inline]
+    fn new_like(&self) -> Self {
+        Self::new()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        CAP
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<&N> {
+        self.as_slice().get(index)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut N> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    #[inline]
+    fn push(&mut self, node: N) {
+        assert!(self.len < CAP, "The vector is full.");
+        self.nodes[self.len].write(node);
+        self.len += 1;
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        assert!(additional <= CAP - self.len, "The vector is full.");
+    }
+
+    #[inline]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) 
+        -> Result<(), TryReserveError> 
+    {
+        if additional <= CAP - self.len {
+            Ok(())
+        } else {
+            Err(capacity_overflow())
+        }
+    }
+
+    #[inline]
+    fn try_reserve_exact(&mut self, additional: usize) 
+        -> Result<(), TryReserveError> 
+    {
+        self.try_reserve(additional)
+    }
+
+    #[inline]
+    fn swap(&mut self, a: usize, b: usize) {
+        self.as_mut_slice().swap(a, b);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            let tail = ptr::slice_from_raw_parts_mut(
+                           self.as_mut_slice()[len..].as_mut_ptr(),
+                           self.len - len);
+            // The length is updated first so a panic while dropping can't
+            // lead to the same nodes being dropped again.
+            self.len = len;
+
+            // SAFETY: The nodes in the tail are initialized, and are no
+            // longer counted by `len`.
+            unsafe { ptr::drop_in_place(tail) }
+        }
+    }
+}
+
+#[cfg(feature = "allocator-api2")]
+impl<N, A> NodeStorage<N> for allocator_api2::vec::Vec<N, A> 
+where
+    A: Allocator + Clone,
+{
+    #[inline]
+    fn new_like(&self) -> Self {
+        allocator_api2::vec::Vec::new_in(self.allocator().clone())
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<&N> {
+        self.as_slice().get(index)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut N> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    #[inline]
+    fn push(&mut self, node: N) {
+        self.push(node);
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    #[inline]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional);
+    }
+
+    /// The error of `allocator-api2` can't be converted into the one from
+    /// `alloc`, so any failure, including an allocation failure, is reported
+    /// as a capacity overflow. The layout the allocator was asked for is lost.
+    ///
+    #[inline]
+    fn try_reserve(&mut self, additional: usize) 
+        -> Result<(), TryReserveError> 
+    {
+        self.try_reserve(additional).map_err(|_| capacity_overflow())
+    }
+
+    #[inline]
+    fn try_reserve_exact(&mut self, additional: usize) 
+        -> Result<(), TryReserveError> 
+    {
+        self.try_reserve_exact(additional).map_err(|_| capacity_overflow())
+    }
+
+    #[inline]
+    fn swap(&mut self, a: usize, b: usize) {
+        self.as_mut_slice().swap(a, b);
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+}
+
+/// Storage that keeps nodes in pages of `PAGE` nodes each. A page is allocated
+/// when the previous one is full, and the existing pages are never moved or
+/// reallocated, so growing the storage doesn't copy the nodes already in it, 
+/// and the addresses of the elements they hold stay the same. This avoids the
+/// cost of reallocating one large buffer as a vector with millions of elements
+/// grows, at the price of an extra indirection on each access. `PAGE` must be
+/// greater than 0, and a power of two makes locating a node cheaper.
+///
+/// Elements are still moved by the operations that relocate nodes, like 
+/// `compact_in_place()`, and by those that rearrange values, like sorting.
+///
+pub struct PagedStorage<N, const PAGE: usize = 1024> {
+    pages : Vec<Vec<N>>,
+    len   : usize,
+}
+
+impl<N, const PAGE: usize> PagedStorage<N, PAGE> {
+    /// Creates empty storage. No page is allocated until a node is added.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        assert!(PAGE > 0, "Pages must hold at least one node.");
+        Self { pages: Vec::new(), len: 0 }
+    }
+
+    /// Returns the page and the position within it of the node at `index`.
+    ///
+    #[inline(always)]
+    fn locate(index: usize) -> (usize, usize) {
+        (index / PAGE, index % PAGE)
+    }
+
+    /// Allocates pages until there's room for `additional` more nodes. 
+    ///
+    fn add_pages(&mut self, additional: usize) {
+        let needed = self.len.saturating_add(additional);
+        while self.capacity() < needed {
+            self.pages.push(Vec::with_capacity(PAGE));
+        }
+    }
+}
+
+impl<N, const PAGE: usize> Default for PagedStorage<N, PAGE> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, const PAGE: usize> Index<usize> for PagedStorage<N, PAGE> {
+    type Output = N;
+
+    #[inline]
+    fn index(&self, index: usize) -> &N {
+        match self.get(index) {
+            Some(node) => node,
+            None => panic!("Index out of bounds."),
+        }
+    }
+}
+
+impl<N, const PAGE: usize> IndexMut<usize> for PagedStorage<N, PAGE> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut N {
+        match self.get_mut(index) {
+            Some(node) => node,
+            None => panic!("Index out of bounds."),
+        }
+    }
+}
+
+impl<N, const PAGE: usize> NodeStorage<N> for PagedStorage<N, PAGE> {
+    #[inline]
+    fn new_like(&self) -> Self {
+        Self::new()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.pages.len() * PAGE
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<&N> {
+        let (page, pos) = Self::locate(index);
+        self.pages.get(page)?.get(pos)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut N> {
+        let (page, pos) = Self::locate(index);
+        self.pages.get_mut(page)?.get_mut(pos)
+    }
+
+    #[inline]
+    fn push(&mut self, node: N) {
+        let (page, _) = Self::locate(self.len);
+        if page == self.pages.len() {
+            self.pages.push(Vec::with_capacity(PAGE));
+        }
+        // A page is never filled past the capacity it was allocated with, so
+        // it never reallocates and its nodes stay where they are.
+        self.pages[page].push(node);
+        self.len += 1;
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.add_pages(additional);
+    }
+
+    #[inline]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.add_pages(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) 
+        -> Result<(), TryReserveError> 
+    {
+        let needed = self.len.checked_add(additional)
+                                 .ok_or_else(capacity_overflow)?;
+        let pages  = needed.div_ceil(PAGE);
+        if pages > self.pages.len() {
+            self.pages.try_reserve(pages - self.pages.len())?;
+            while self.pages.len() < pages {
+                let mut page = Vec::new();
+                page.try_reserve_exact(PAGE)?;
+                self.pages.push(page);
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn try_reserve_exact(&mut self, additional: usize) 
+        -> Result<(), TryReserveError> 
+    {
+        self.try_reserve(additional)
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        let (pa, ia) = Self::locate(a);
+        let (pb, ib) = Self::locate(b);
+        if pa == pb {
+            self.pages[pa].swap(ia, ib);
+        } else {
+            let (lo, hi) = self.pages.split_at_mut(pa.max(pb));
+            let (nlo, nhi) = if pa < pb { (ia, ib) } else { (ib, ia) };
+            core::mem::swap(&mut lo[pa.min(pb)][nlo], &mut hi[0][nhi]);
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            let (page, pos) = Self::locate(len);
+            // The pages stay allocated, as the capacity of a `Vec` does.
+            for (i, nodes) in self.pages.iter_mut().enumerate().skip(page) {
+                nodes.truncate(if i == page { pos } else { 0 });
+            }
+            self.len = len;
+        }
+    }
+}
+
+/// Returns the error for storage that can't make room for more nodes. 
+/// `TryReserveError` can't be constructed directly, so the error is taken from
+/// a request no vector could satisfy. It fails on the capacity check, before
+/// anything is allocated.
+///
+fn capacity_overflow() -> TryReserveError {
+    Vec::<u8>::new().try_reserve(usize::MAX).unwrap_err()
+}
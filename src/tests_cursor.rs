@@ -2,6 +2,7 @@
 
 use crate::linked_vector::*;
 use crate::cursor::*;
+use crate::error::*;
 
 #[test]
 fn cursor() {
@@ -107,6 +108,20 @@ fn cursor_insert() {
     assert_eq!(lv.to_vec(), vec![1, 2, 3, 4, 10, 5, 6, 7, 8, 9]);
 }
 
+#[test]
+fn cursor_array() {
+    let mut lv = ArrayLinkedVector::<i32, 4>::new_array();
+    lv.extend([1, 2, 3]);
+    let h2 = lv.handle(1).unwrap();
+    let mut cursor = lv.cursor_mut(h2);
+
+    cursor.insert(4);
+    let hend = cursor.move_to_back().unwrap();
+
+    assert_eq!(lv.back_node(), Some(hend));
+    assert_eq!(lv.to_vec(), vec![1, 4, 2, 3]);
+}
+
 #[test]
 #[cfg(feature = "cursor-remove")]
 fn cursor_remove() {
@@ -141,6 +156,28 @@ fn cursor_remove() {
     assert_eq!(lv.to_vec(), vec![1, 2, 3, 4, 6, 7, 8]);
 }
 
+#[test]
+#[cfg(feature = "cursor-remove")]
+fn cursor_remove_compaction_policy() {
+    let mut lv = LinkedVector::from_iter(0..10);
+    let h0 = lv.front_node().unwrap();
+    let h9 = lv.back_node().unwrap();
+
+    lv.set_compaction_policy(Some(
+        crate::compaction::CompactionPolicy::new(0.25).min_nodes(0)));
+
+    let mut cursor = lv.cursor_mut(h0);
+
+    for _ in 0..5 {
+        cursor.remove();
+    }
+    // The vector isn't compacted out from under the cursor.
+    assert_eq!(*cursor, 5);
+    assert_eq!(cursor.node(), lv.handle(0).unwrap());
+    assert_eq!(lv.vacant_count(), 5);
+    assert_eq!(lv[h9], 9);
+}
+
 #[test]
 fn cursor_deref() {
     let lv = LinkedVector::from([1, 2, 3, 4, 5, 6, 7, 8, 9]);
@@ -169,4 +206,47 @@ fn cursor_mut_deref() {
     assert_eq!(*cursor, 7);
 
     assert_eq!(lv.to_vec(), vec![1, 2, 3, 4, 10, 6, 7, 8, 9]);
-}
\ No newline at end of file
+}
+
+#[test]
+fn try_cursor() {
+    let lv1 = LinkedVector::from([1, 2, 3]);
+    let h2  = lv1.handle(1).unwrap();
+    let mut cursor = lv1.try_cursor(h2).unwrap();
+
+    cursor.move_next();
+    assert_eq!(*cursor, 3);
+
+    let lv2 = LinkedVector::<i32>::new();
+    assert_eq!(lv2.try_cursor(h2).err(), Some(LinkedVectorError::EmptyList));
+    assert_eq!(lv1.try_cursor(HNode::default()).err(), 
+               Some(LinkedVectorError::InvalidHandle));
+}
+
+#[test]
+fn typed_handles() {
+    let mut lv = LinkedVector::from([1, 2, 3, 4]);
+    let h2: TypedHNode<i32> = lv.handle(1).unwrap().into();
+    let h4: TypedHNode<i32> = lv.back_node().unwrap().into();
+
+    let mut cursor = lv.cursor_mut(h2);
+    assert_eq!(*cursor, 2);
+
+    cursor.move_to_handle(h4);
+    *cursor = 40;
+
+    assert_eq!(lv.to_vec(), vec![1, 2, 3, 40]);
+}
+
+#[test]
+fn cursor_base_dyn() {
+    let lv = LinkedVector::from([1, 2, 3]);
+    let h3 = lv.back_node().unwrap();
+    let mut cursor = lv.cursor_front().unwrap();
+    let base: &mut dyn CursorBase<i32> = &mut cursor;
+
+    base.move_to(h3);
+
+    assert_eq!(base.node(), h3);
+    assert_eq!(base.move_prev(), lv.handle(1));
+}
@@ -1,13 +1,22 @@
-#![allow(unused_variables)]
+#![allow(unused_variables, clippy::bool_assert_comparison)]
 
+use core::cell::Cell;
 use core::cmp::Reverse;
+use core::mem::size_of;
+use core::ops::Bound;
+use core::panic::{RefUnwindSafe, UnwindSafe};
 use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
+use crate::compaction::*;
+use crate::error::*;
 use crate::linked_vector::*;
+use crate::recycle::*;
 
 #[test]
 #[should_panic]
-#[cfg(debug_assertions)]
+#[cfg(feature = "generational-handles")]
 fn expired_handles_1() {
     let mut lv = LinkedVector::new();
     let h1 = lv.push_back(1);
@@ -18,12 +27,12 @@ fn expired_handles_1() {
 
     lv.push_back(4); // This will recycle node pointed to by h2.
 
-    lv.get(h2);
+    let _ = lv[h2];
 }
 
 #[test]
 #[should_panic]
-#[cfg(debug_assertions)]
+#[cfg(feature = "generational-handles")]
 fn expired_handles_2() {
     let mut lv = LinkedVector::new();
     let h1 = lv.push_back(1);
@@ -32,11 +41,11 @@ fn expired_handles_2() {
 
     lv.remove(h2);
 
-    lv.get(h2);
+    let _ = lv[h2];
 }
 
 #[test]
-#[cfg(debug_assertions)]
+#[cfg(feature = "generational-handles")]
 fn expired_handles_3() {
     let mut lv = LinkedVector::new();
     let h1 = lv.push_back(1);
@@ -60,9 +69,43 @@ fn expired_handles_3() {
     lv.get(h3);
 }
 
+#[test]
+#[cfg(not(feature = "optionless-accessors"))]
+fn expired_handles_none() {
+    let mut lv = LinkedVector::from([1, 2, 3]);
+    let h1 = lv.handle(0).unwrap();
+    let h2 = lv.handle(1).unwrap();
+
+    assert_eq!(lv.remove(h2), Some(2));
+    assert_eq!(lv.get(h2), None);
+    assert_eq!(lv.get_mut(h2), None);
+    assert_eq!(lv.remove(h2), None);
+    assert_eq!(lv.next_node(h2), None);
+    assert_eq!(lv.prev_node(h2), None);
+    assert_eq!(lv.get(h1), Some(&1));
+    assert_eq!(lv.to_vec(), vec![1, 3]);
+}
+
+#[test]
+#[cfg(all(feature = "generational-handles", 
+          not(feature = "optionless-accessors")))]
+fn expired_handles_recycled_none() {
+    let mut lv = LinkedVector::from([1, 2, 3]);
+    let h2 = lv.handle(1).unwrap();
+
+    lv.remove(h2);
+    lv.push_back(4); // This will recycle node pointed to by h2.
+
+    assert_eq!(lv.get(h2), None);
+    assert_eq!(lv.get_mut(h2), None);
+    assert_eq!(lv.remove(h2), None);
+    assert_eq!(lv.next_value(h2), None);
+    assert_eq!(lv.to_vec(), vec![1, 3, 4]);
+}
+
 #[test]
 #[should_panic]
-#[cfg(debug_assertions)]
+#[cfg(feature = "checked-handles")]
 fn foreign_handles() {
     let mut lv1 = LinkedVector::new();
     let     lv2 = LinkedVector::from([1, 2, 3]);
@@ -70,7 +113,62 @@ fn foreign_handles() {
     let h2 = lv1.push_back(2);
     let h3 = lv1.push_back(3);
 
-    lv2.get(h1); // h1 belongs to lv1.
+    let _ = lv2[h1]; // h1 belongs to lv1.
+}
+
+#[test]
+#[cfg(feature = "allocator-api2")]
+fn alloc_linked_vector() {
+    use core::alloc::Layout;
+    use core::cell::Cell;
+    use core::ptr::NonNull;
+    use allocator_api2::alloc::{AllocError, Allocator, Global};
+
+    #[derive(Clone)]
+    struct Counting(Rc<Cell<usize>>);
+
+    unsafe impl Allocator for Counting {
+        fn allocate(&self, layout: Layout) 
+            -> Result<NonNull<[u8]>, AllocError> 
+        {
+            self.0.set(self.0.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.0.set(self.0.get() - 1);
+            Global.deallocate(ptr, layout)
+        }
+    }
+
+    let live = Rc::new(Cell::new(0));
+    let mut lv1 = AllocLinkedVector::<i32, _>::with_capacity_in(
+                      4, Counting(live.clone()));
+
+    assert_eq!(live.get(), 1);
+
+    lv1.extend([1, 2, 3, 4]);
+    let h3 = lv1.handle(2).unwrap();
+    let lv2 = lv1.split_off(h3);
+    let lv3 = lv2.clone();
+
+    assert_eq!(lv1.to_vec(), vec![1, 2]);
+    assert_eq!(lv2.to_vec(), vec![3, 4]);
+    assert_eq!(lv3.to_vec(), vec![3, 4]);
+    assert_eq!(live.get(), 3);
+
+    drop(lv1);
+    drop(lv2);
+    drop(lv3);
+
+    assert_eq!(live.get(), 0);
+
+    let mut lv4 = AllocLinkedVector::<i32, _>::new_in(Counting(live.clone()));
+
+    assert_eq!(live.get(), 0);
+    assert!(lv4.try_reserve(10).is_ok());
+    assert!(lv4.try_reserve(usize::MAX).is_err());
+    assert_eq!(Rc::strong_count(&lv4.allocator().0), 2);
 }
 
 #[test] 
@@ -83,6 +181,180 @@ fn append() {
     assert_eq!(lv1.to_vec(), vec![1, 2, 3, 4, 5, 6]);
 }
 
+#[test]
+fn append_recycled() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4]);
+    let mut lv2 = LinkedVector::from([5, 6, 7, 8]);
+    let h2 = lv1.handle(1).unwrap();
+
+    lv1.remove(h2);
+    lv2.pop_front();
+    lv2.pop_back();
+
+    lv1.append(&mut lv2);
+
+    assert_eq!(lv1.to_vec(), vec![1, 3, 4, 6, 7]);
+    assert_eq!(lv1.iter().rev().copied().collect::<Vec<_>>(), 
+               vec![7, 6, 4, 3, 1]);
+    assert_eq!(lv1.len(), 5);
+    assert!(lv2.is_empty());
+
+    // The recycle lists of both vectors are joined.
+    let cap = lv1.capacity();
+    lv1.extend([9, 10, 11]);
+    assert_eq!(lv1.capacity(), cap);
+    assert_eq!(lv1.to_vec(), vec![1, 3, 4, 6, 7, 9, 10, 11]);
+
+    let mut lv3 = LinkedVector::new();
+    lv3.append(&mut lv1);
+    assert_eq!(lv3.to_vec(), vec![1, 3, 4, 6, 7, 9, 10, 11]);
+
+    lv2.push_back(1);
+    lv2.append(&mut LinkedVector::new());
+    assert_eq!(lv2.to_vec(), vec![1]);
+}
+
+#[test]
+fn append_index_overflow() {
+    let mut lv1 = LinkedVector::<u16, u16>::new_indexed();
+    let mut lv2 = LinkedVector::<u16, u16>::new_indexed();
+    lv1.extend(0..40000);
+    lv2.extend(0..40000);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        lv1.append(&mut lv2);
+    }));
+    assert!(result.is_err());
+
+    // Both vectors are left as they were.
+    assert_eq!(lv1.len(), 40000);
+    assert_eq!(lv2.len(), 40000);
+    assert_eq!(lv1.iter().count(), 40000);
+    assert!(lv2.iter().copied().eq(0..40000));
+}
+
+#[test]
+#[cfg(feature = "generational-handles")]
+fn append_expires_handles() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let mut lv2 = LinkedVector::from([4, 5, 6]);
+    let h3 = lv1.back_node().unwrap();
+    let h4 = lv2.front_node().unwrap();
+
+    lv1.clear();
+    lv1.append(&mut lv2);
+
+    // The handles to the nodes `lv2` gave up don't match the new elements.
+    lv2.push_back(42);
+
+    assert_eq!(lv2.try_get(h4), Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv2.to_vec(), vec![42]);
+
+    // Nor do the handles to the nodes `lv1` gave up match the appended ones.
+    assert_eq!(lv1.try_get(h3), Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1.to_vec(), vec![4, 5, 6]);
+}
+
+#[test]
+fn append_with_remap() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let mut lv2 = LinkedVector::from([4, 5, 6]);
+    let h1 = lv1.handle(0).unwrap();
+    let hs = lv2.handles().collect::<Vec<_>>();
+
+    lv2.remove(hs[1]);
+
+    let remap = lv1.append_with_remap(&mut lv2);
+
+    assert_eq!(remap.len(), 2);
+    assert_eq!(lv1[remap.get(hs[0]).unwrap()], 4);
+    assert_eq!(lv1[remap.get(hs[2]).unwrap()], 6);
+    assert_eq!(lv1[h1], 1);
+    assert_eq!(lv1.to_vec(), vec![1, 2, 3, 4, 6]);
+}
+
+#[test]
+fn array_linked_vector() {
+    let mut lv = ArrayLinkedVector::<i32, 3>::new_array();
+    let h1 = lv.push_back(1);
+    let h2 = lv.push_back(2);
+    let h3 = lv.push_front(0);
+
+    assert_eq!(lv.capacity(), 3);
+    assert_eq!(lv.try_push_back(4).unwrap_err().into_inner(), 4);
+    assert_eq!(lv.try_push_front(5).unwrap_err().into_inner(), 5);
+    assert_eq!(lv.to_vec(), vec![0, 1, 2]);
+
+    lv.remove(h2);
+
+    let h4 = lv.try_push_back(3).unwrap();
+
+    assert_eq!(lv.to_vec(), vec![0, 1, 3]);
+    assert_eq!(lv.next_node(h1), Some(h4));
+    assert_eq!(lv.prev_node(h1), Some(h3));
+}
+
+#[test]
+fn array_linked_vector_ops() {
+    let mut lv1 = ArrayLinkedVector::<i32, 6>::new_array();
+    let mut lv2 = ArrayLinkedVector::<i32, 6>::new_array();
+
+    lv1.extend([1, 2, 3]);
+    lv2.extend([4, 5, 6]);
+
+    let h2 = lv1.handle(1).unwrap();
+    lv1.remove(h2);
+    lv1.append(&mut lv2);
+
+    assert_eq!(lv1.to_vec(), vec![1, 3, 4, 5, 6]);
+    assert!(lv2.is_empty());
+
+    let h5 = lv1.handle(3).unwrap();
+    let mut lv3 = lv1.split_off(h5);
+
+    assert_eq!(lv1.to_vec(), vec![1, 3, 4]);
+    assert_eq!(lv3.to_vec(), vec![5, 6]);
+
+    lv1.compact_in_place();
+
+    assert_eq!(lv1.to_vec(), vec![1, 3, 4]);
+    assert_eq!(lv1.clone(), lv1);
+
+    lv3.clear();
+    lv3.extend([7, 8, 9, 10, 11, 12]);
+
+    assert_eq!(lv3.len(), 6);
+    assert_eq!(lv3.into_iter().sum::<i32>(), 57);
+}
+
+#[test]
+#[should_panic]
+fn array_linked_vector_full() {
+    let mut lv = ArrayLinkedVector::<i32, 2>::new_array();
+    lv.push_back(1);
+    lv.push_back(2);
+    lv.push_back(3);
+}
+
+#[test]
+fn array_linked_vector_drop() {
+    let rc = Rc::new(0);
+    let mut lv = ArrayLinkedVector::<Rc<i32>, 4>::new_array();
+    let h1 = lv.push_back(rc.clone());
+    lv.push_back(rc.clone());
+    lv.push_back(rc.clone());
+
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    lv.remove(h1);
+
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    drop(lv);
+
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
 #[test]
 fn back() {
     let mut lv1 = LinkedVector::new();
@@ -115,6 +387,30 @@ fn clear() {
     assert_eq!(lv1.len(), 0);
 }
 
+#[test]
+#[cfg(feature = "generational-handles")]
+fn clear_expires_handles() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h1 = lv1.handle(0).unwrap();
+    let h2 = lv1.handle(1).unwrap();
+
+    lv1.remove(h2);
+    lv1.clear();
+
+    let h4 = lv1.push_back(4); // Takes the slot h1 referred to.
+    let h5 = lv1.push_back(5);
+
+    assert_eq!(lv1.try_get(h1), Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1.try_get(h2), Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1[h4], 4);
+    assert_eq!(lv1[h5], 5);
+
+    lv1.clear();
+    lv1.push_back(6);
+
+    assert_eq!(lv1.try_get(h4), Err(LinkedVectorError::ExpiredHandle));
+}
+
 #[test]
 fn clone() {
     let mut lv1 = LinkedVector::new();
@@ -126,6 +422,137 @@ fn clone() {
     assert_eq!(lv1.len(), lv2.len());
 }
 
+#[test]
+fn clone_with_layout() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4]);
+    let h2 = lv1.handle(1).unwrap();
+
+    lv1.remove(h2);
+
+    let mut lv2 = lv1.clone_with_layout();
+
+    assert_eq!(lv1, lv2);
+    assert_eq!(lv2.handles().map(|h| h.0).collect::<Vec<_>>(), 
+               lv1.handles().map(|h| h.0).collect::<Vec<_>>());
+    assert_eq!(lv2.push_back(5).0, lv1.push_back(5).0);
+
+    let h1 = lv2.handle(0).unwrap();
+    let h4 = lv2.handle(2).unwrap();
+
+    lv2[h4] = 40;
+    lv2.remove(h1);
+
+    assert_eq!(lv1.to_vec(), vec![1, 3, 4, 5]);
+    assert_eq!(lv2.to_vec(), vec![3, 40, 5]);
+}
+
+#[test]
+#[cfg(feature = "checked-handles")]
+fn clone_with_layout_translate_handles() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4]);
+    let h1 = lv1.handle(0).unwrap();
+    let h2 = lv1.handle(1).unwrap();
+    let h4 = lv1.handle(3).unwrap();
+
+    lv1.remove(h2);
+
+    let mut lv2 = lv1.clone_with_layout();
+
+    assert_eq!(lv2.try_get(h1), Err(LinkedVectorError::ForeignHandle));
+    assert_eq!(lv2.translate_handle(&lv1, h2), None);
+
+    let h1_2 = lv2.translate_handle(&lv1, h1).unwrap();
+    let h4_2 = lv2.translate_handle(&lv1, h4).unwrap();
+
+    assert_eq!(lv2[h1_2], 1);
+    assert_eq!(lv2[h4_2], 4);
+    assert_eq!(lv1.translate_handle(&lv2, h4_2), Some(h4));
+
+    lv2.remove(h1_2);
+
+    assert_eq!(lv2.translate_handle(&lv1, h1), None);
+    assert_eq!(lv1.translate_handle(&lv2, h4_2), Some(h4));
+
+    // Unrelated vectors don't translate each other's handles.
+    let lv3 = lv1.clone();
+    let lv4 = lv3.clone_with_layout();
+
+    assert_eq!(lv3.translate_handle(&lv1, h4), None);
+    assert_eq!(lv4.translate_handle(&lv1, h4), None);
+}
+
+#[test]
+#[cfg(feature = "checked-handles")]
+fn clone_with_layout_diverged_handles() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h2 = lv1.handle(1).unwrap();
+    let h3 = lv1.handle(2).unwrap();
+    let mut lv2 = lv1.clone_with_layout();
+    let h2_2 = lv2.translate_handle(&lv1, h2).unwrap();
+
+    // Both vectors reuse the same node for different elements.
+    lv1.remove(h2);
+    lv2.remove(h2_2);
+    let h4 = lv1.push_back(4);
+    let h5 = lv2.push_back(5);
+
+    assert_eq!(h4.0, h5.0);
+    assert_eq!(lv2.try_get(h4), Err(LinkedVectorError::ForeignHandle));
+    assert_eq!(lv2.translate_handle(&lv1, h4), None);
+    assert_eq!(lv1.translate_handle(&lv2, h5), None);
+
+    // New nodes at the end of either vector aren't confused either.
+    let h6 = lv1.push_back(6);
+    let h7 = lv2.push_back(7);
+
+    assert_eq!(h6.0, h7.0);
+    assert_eq!(lv2.translate_handle(&lv1, h6), None);
+    assert_eq!(lv1.translate_handle(&lv2, h7), None);
+
+    // Relocated elements aren't translated.
+    lv2.compact_in_place();
+
+    assert_eq!(lv2.translate_handle(&lv1, h3), None);
+    assert_eq!(lv2.to_vec(), vec![1, 3, 5, 7]);
+}
+
+#[test]
+#[cfg(feature = "generational-handles")]
+fn clone_with_layout_expired_handles() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h2 = lv1.handle(1).unwrap();
+
+    lv1.remove(h2);
+    lv1.push_back(4);
+
+    let lv2 = lv1.clone_with_layout();
+
+    #[cfg(feature = "checked-handles")]
+    assert_eq!(lv2.translate_handle(&lv1, h2), None);
+
+    #[cfg(not(feature = "checked-handles"))]
+    assert_eq!(lv2.try_get(h2), Err(LinkedVectorError::ExpiredHandle));
+}
+
+#[test]
+fn clone_with_layout_array() {
+    let mut lv1 = ArrayLinkedVector::<Rc<i32>, 3>::new_array();
+    let rc = Rc::new(0);
+    let h1 = lv1.push_back(rc.clone());
+    lv1.push_back(rc.clone());
+    lv1.remove(h1);
+
+    let lv2 = lv1.clone_with_layout();
+
+    assert_eq!(lv2.len(), 1);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    drop(lv1);
+    drop(lv2);
+
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
 #[test]
 fn compact() {
     let lv1 = LinkedVector::from([1, 2, 3, 4, 5, 6, 7, 8, 9]);
@@ -135,7 +562,7 @@ fn compact() {
 
 #[test]
 #[should_panic]
-#[cfg(debug_assertions)]
+#[cfg(feature = "checked-handles")]
 fn compact_2() {
     let mut lv1 = LinkedVector::from([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
 
@@ -150,7 +577,207 @@ fn compact_2() {
     assert_eq!(lv2.to_vec(), vec![0, 1, 2, 3, 42, 4, 6, 7, 8, 9]);
 
     // Should panic here. Old handle should be foreign to new vector.
-    lv2.get(h4);
+    let _ = lv2[h4];
+}
+
+#[test]
+fn compact_in_place() {
+    let mut lv1 = LinkedVector::new();
+    let mut hs  = vec![];
+
+    for i in 0..20 {
+        hs.push(lv1.push_back(i));
+    }
+    for &h in hs.iter().step_by(3) {
+        lv1.remove(h);
+    }
+    let h = lv1.handle(3).unwrap();
+    lv1.push_front(100);
+    lv1.insert(h, 200);
+
+    let before = lv1.to_vec();
+    let remap  = lv1.compact_in_place();
+
+    assert_eq!(lv1.to_vec(), before);
+    assert_eq!(remap.len(), lv1.len());
+    assert_eq!(lv1.handles().rev().map(|h| lv1[h]).collect::<Vec<_>>(), 
+               before.iter().rev().copied().collect::<Vec<_>>());
+
+    for (i, &h) in hs.iter().enumerate() {
+        if i % 3 != 0 {
+            assert_eq!(lv1[remap.get(h).unwrap()], i);
+        }
+    }
+    for (hold, hnew) in remap.iter() {
+        assert!(lv1.try_get(hnew).is_ok());
+    }
+    lv1.push_back(300);
+    lv1.pop_front();
+    assert_eq!(lv1.back(), Some(&300));
+
+    let mut lv2 = LinkedVector::<i32>::new();
+    assert!(lv2.compact_in_place().is_empty());
+}
+
+#[test]
+#[cfg(feature = "generational-handles")]
+fn compact_in_place_expires_moved_handles() {
+    let mut lv1 = LinkedVector::from([0, 1, 2, 3, 4]);
+    let h0 = lv1.handle(0).unwrap();
+    let h1 = lv1.handle(1).unwrap();
+    let h4 = lv1.handle(4).unwrap();
+
+    lv1.remove(h1);
+
+    let remap = lv1.compact_in_place();
+
+    assert_eq!(remap.get(h0), Some(h0)); // Already in position.
+    assert_eq!(remap.get(h1), None);
+    assert_ne!(remap.get(h4), Some(h4));
+    assert!(lv1.try_get(h4).is_err());
+    assert_eq!(lv1[remap.get(h4).unwrap()], 4);
+}
+
+#[test]
+fn compaction_policy() {
+    let mut lv1 = LinkedVector::from_iter(0..100);
+    let h99 = lv1.back_node().unwrap();
+    let moved = Arc::new(Mutex::new(None));
+    let sink = moved.clone();
+
+    lv1.set_compaction_policy(Some(
+        CompactionPolicy::new(0.5)
+            .on_compact(move |remap| *sink.lock().unwrap() = remap.get(h99))));
+
+    assert_eq!(lv1.compaction_policy().unwrap().threshold(), 0.5);
+
+    for _ in 0..50 {
+        lv1.pop_front();
+    }
+    assert_eq!(lv1.vacant_count(), 50);
+    assert!(moved.lock().unwrap().is_none());
+
+    lv1.pop_front();
+
+    let h99 = moved.lock().unwrap().unwrap();
+
+    assert_eq!(lv1.vacant_count(), 0);
+    assert_eq!(lv1[h99], 99);
+    assert_eq!(lv1.to_vec(), (51..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn compaction_policy_send_callback() {
+    fn assert_auto_traits<T>(_: &T) 
+    where
+        T: Send + Sync + UnwindSafe + RefUnwindSafe,
+    {}
+    let mut lv1 = LinkedVector::from_iter(0..100);
+    let calls = Cell::new(0);
+
+    // A `Cell` is `Send` but not `Sync`, and the vector keeps its traits.
+    lv1.set_compaction_policy(Some(
+        CompactionPolicy::new(0.5)
+            .on_compact(move |_| {
+                calls.set(calls.get() + 1);
+                panic!("compaction {}", calls.get());
+            })));
+
+    assert_auto_traits(&lv1);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        for _ in 0..51 {
+            lv1.pop_front();
+        }
+    }));
+
+    // The panicking callback was dropped with its policy.
+    assert!(result.is_err());
+    assert!(lv1.compaction_policy().is_none());
+    assert_eq!(lv1.vacant_count(), 0);
+    assert_eq!(lv1.to_vec(), (51..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn compaction_policy_min_nodes() {
+    let mut lv1 = LinkedVector::from_iter(0..20);
+    lv1.set_compaction_policy(Some(CompactionPolicy::new(0.5)));
+
+    for _ in 0..15 {
+        lv1.pop_back();
+    }
+    assert_eq!(lv1.vacant_count(), 15);
+
+    lv1.set_compaction_policy(Some(CompactionPolicy::new(0.5).min_nodes(0)));
+    lv1.pop_back();
+
+    assert_eq!(lv1.vacant_count(), 0);
+    assert_eq!(lv1.to_vec(), vec![0, 1, 2, 3]);
+
+    lv1.set_compaction_policy(None);
+    lv1.pop_back();
+    lv1.pop_back();
+
+    assert_eq!(lv1.vacant_count(), 2);
+}
+
+#[test]
+fn compaction_policy_out_of_order() {
+    let mut lv1 = LinkedVector::new();
+    for i in 0..100 {
+        lv1.push_front(i);
+    }
+    let h0 = lv1.back_node().unwrap();
+    let count = Arc::new(Mutex::new(0));
+    let sink = count.clone();
+
+    assert!(lv1.fragmentation() > 0.99);
+
+    lv1.set_compaction_policy(Some(
+        CompactionPolicy::new(0.5)
+            .min_nodes(0)
+            .on_compact(move |_| *sink.lock().unwrap() += 1)));
+
+    let h1 = lv1.prev_node(h0).unwrap();
+    assert_eq!(lv1.try_remove(h1), Ok(1));
+
+    // No vacant nodes are left, but the links had to be put in order.
+    assert_eq!(*count.lock().unwrap(), 1);
+    assert_eq!(lv1.vacant_count(), 0);
+    assert_eq!(lv1.fragmentation(), 0.0);
+    assert_eq!(lv1.front(), Some(&99));
+    assert_eq!(lv1.back(), Some(&0));
+
+    // Removals that leave the vector compact enough don't compact it.
+    for _ in 0..5 {
+        lv1.pop_back();
+    }
+    assert_eq!(*count.lock().unwrap(), 1);
+    assert_eq!(lv1.vacant_count(), 5);
+}
+
+#[test]
+#[cfg(feature = "generational-handles")]
+fn compact_in_place_expires_released_handles() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5]);
+    let h1 = lv1.handle(0).unwrap();
+    let h4 = lv1.handle(3).unwrap();
+    let h5 = lv1.handle(4).unwrap();
+
+    lv1.remove(h5);
+    lv1.remove(h1);
+
+    let remap = lv1.compact_in_place();
+
+    // The slots of h4 and h5 are released, then taken again.
+    lv1.push_back(55);
+    let h6 = lv1.push_back(66);
+
+    assert_eq!(lv1.try_get(h5), Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1.try_get(h4), Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1[h6], 66);
+    assert_eq!(lv1[remap.get(h4).unwrap()], 4);
+    assert_eq!(lv1.to_vec(), vec![2, 3, 4, 55, 66]);
 }
 
 #[test]
@@ -181,6 +808,56 @@ fn default() {
     assert_eq!(lv1.is_empty(), true);
 }
 
+#[test]
+fn drain() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5, 6, 7, 8]);
+    let hs = lv1.handles().collect::<Vec<_>>();
+
+    assert_eq!(lv1.drain(hs[1]..hs[3]).collect::<Vec<_>>(), vec![2, 3]);
+    assert_eq!(lv1.drain(hs[5]..=hs[6]).rev().collect::<Vec<_>>(), 
+               vec![7, 6]);
+    assert_eq!(lv1.to_vec(), vec![1, 4, 5, 8]);
+    assert_eq!(lv1.drain(hs[3]..hs[3]).count(), 0);
+    assert_eq!(lv1.drain((Bound::Excluded(hs[4]), Bound::Unbounded))
+                  .collect::<Vec<_>>(), vec![8]);
+    assert_eq!(lv1.drain(..hs[3]).collect::<Vec<_>>(), vec![1]);
+    assert_eq!(lv1.to_vec(), vec![4, 5]);
+    assert_eq!(lv1.iter().rev().copied().collect::<Vec<_>>(), vec![5, 4]);
+
+    let it = lv1.drain(..);
+    assert_eq!(it.len(), 2);
+    drop(it);
+    assert!(lv1.is_empty());
+    assert_eq!(lv1.drain(..).count(), 0);
+}
+
+#[test]
+fn drain_dropped_early() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5, 6]);
+    let h2 = lv1.handle(1).unwrap();
+    let h1 = lv1.handle(0).unwrap();
+    let h6 = lv1.handle(5).unwrap();
+
+    let mut it = lv1.drain(h2..h6);
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.next_back(), Some(5));
+    drop(it);
+
+    assert_eq!(lv1.to_vec(), vec![1, 6]);
+    assert_eq!(lv1[h1], 1);
+    assert_eq!(lv1[h6], 6);
+}
+
+#[test]
+#[should_panic]
+fn drain_bad_range() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4]);
+    let h1 = lv1.handle(0).unwrap();
+    let h3 = lv1.handle(2).unwrap();
+
+    lv1.drain(h3..h1);
+}
+
 #[test]
 fn eq() {
     let mut lv1 = LinkedVector::new();
@@ -210,6 +887,55 @@ fn extend() {
     assert_eq!(lv1.len(), 6);
 }
 
+#[test]
+fn typed_handles() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h1: TypedHNode<i32> = lv1.front_node().unwrap().into();
+    let h4: TypedHNode<i32> = lv1.push_back(4).into();
+
+    assert_eq!(h1, lv1.front_node().unwrap());
+    assert_eq!(HNode::from(h4), lv1.back_node().unwrap());
+    assert_eq!(lv1[h1], 1);
+
+    lv1[h4] = 40;
+    lv1.insert_after(h1, 10);
+    lv1.move_to_front(h4);
+
+    assert_eq!(lv1.to_vec(), vec![40, 1, 10, 2, 3]);
+    assert_eq!(lv1.next_node(h4), Some(h1.into()));
+    assert_eq!(lv1.try_get(h1), Ok(&1));
+    assert_eq!(lv1.try_get(TypedHNode::default()), 
+               Err(LinkedVectorError::InvalidHandle));
+
+    lv1.remove(h1);
+
+    assert_eq!(lv1.to_vec(), vec![40, 10, 2, 3]);
+    assert_eq!(core::mem::size_of::<TypedHNode<i32>>(), 
+               core::mem::size_of::<HNode>());
+}
+
+#[test]
+fn typed_handle_ranges() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5, 6]);
+    let hs = lv1.handles().map(TypedHNode::<i32>::from).collect::<Vec<_>>();
+
+    assert_eq!(lv1.iter_range(hs[1]..hs[3]).collect::<Vec<_>>(), vec![&2, &3]);
+    assert_eq!(lv1.handles_range(..=hs[1]).collect::<Vec<_>>(), 
+               vec![hs[0].into(), hs[1].into()]);
+    assert_eq!(lv1.iter_range((Bound::Excluded(hs[3]), Bound::Unbounded))
+                  .collect::<Vec<_>>(), vec![&5, &6]);
+
+    lv1.iter_range_mut(hs[4]..).for_each(|v| *v *= 10);
+
+    assert_eq!(lv1.drain(..hs[1]).collect::<Vec<_>>(), vec![1]);
+    assert_eq!(lv1.to_vec(), vec![2, 3, 4, 50, 60]);
+
+    let remap = lv1.compact_in_place();
+
+    assert_eq!(lv1[remap.get(hs[5]).unwrap()], 60);
+    assert_eq!(remap.get(hs[0]), None);
+}
+
 #[test]
 fn test_drop() {
     let mut lv1 = LinkedVector::new();
@@ -219,6 +945,106 @@ fn test_drop() {
     drop(lv1); // Should not panic.
 }
 
+#[test]
+fn drop_values() {
+    let rc  = Rc::new(());
+    let mut lv1 = LinkedVector::new();
+    let hs = (0..5).map(|_| lv1.push_back(rc.clone())).collect::<Vec<_>>();
+    assert_eq!(Rc::strong_count(&rc), 6);
+
+    lv1.remove(hs[1]);
+    lv1.pop_front();
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    let lv2 = lv1.split_off(hs[3]);
+    lv1.push_back(rc.clone());
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    drop(lv1);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    let mut iter = lv2.into_iter();
+    iter.next();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+#[cfg(feature = "checked-handles")]
+fn checked_handles_any_profile() {
+    // The checks are chosen by feature rather than by `debug_assertions`, so
+    // this holds for `cargo test --release` as well.
+    assert_eq!(size_of::<HNode>(), 3 * size_of::<usize>());
+
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let     lv2 = LinkedVector::from([1, 2, 3]);
+    let h1 = lv1.handle(0).unwrap();
+    let h2 = lv1.handle(1).unwrap();
+
+    lv1.remove(h2);
+    lv1.push_back(4); // This will recycle node pointed to by h2.
+
+    assert_eq!(lv1.try_get(h2), Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv2.try_get(h1), Err(LinkedVectorError::ForeignHandle));
+
+    #[cfg(not(feature = "optionless-accessors"))]
+    {
+        assert_eq!(lv1.get(h2), None);
+        assert_eq!(lv2.get(h1), None);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "generational-handles"))]
+fn unchecked_handles_any_profile() {
+    // Without the checks, handles are bare indexes in every build profile.
+    assert_eq!(size_of::<HNode>(), size_of::<usize>());
+    assert_eq!(size_of::<HNode<u32>>(), size_of::<u32>());
+}
+
+#[test]
+fn node_size() {
+    // A node is no larger than its value, its links, and its generation.
+    #[cfg(feature = "generational-handles")]
+    type Parts<T, I> = (T, I, I, I);
+    #[cfg(not(feature = "generational-handles"))]
+    type Parts<T, I> = (T, I, I);
+
+    assert_eq!(size_of::<Node<u32, u32>>(), size_of::<Parts<u32, u32>>());
+    assert_eq!(size_of::<Node<u64, u32>>(), size_of::<Parts<u64, u32>>());
+    assert_eq!(size_of::<Node<u16, u16>>(), size_of::<Parts<u16, u16>>());
+    assert_eq!(size_of::<Node<u64, usize>>(), 
+               size_of::<Parts<u64, usize>>());
+
+    #[cfg(feature = "generational-handles")]
+    assert_eq!(size_of::<Node<u32, u32>>(), 16);
+    #[cfg(not(feature = "generational-handles"))]
+    assert_eq!(size_of::<Node<u32, u32>>(), 12);
+}
+
+#[test]
+fn extract_if() {
+    let mut lv1 = LinkedVector::new();
+    let hs = (0..10).map(|i| lv1.push_back(i)).collect::<Vec<_>>();
+
+    let odds = lv1.extract_if(|v| *v % 2 == 1).collect::<Vec<_>>();
+
+    assert_eq!(odds, vec![1, 3, 5, 7, 9]);
+    assert_eq!(lv1.to_vec(), vec![0, 2, 4, 6, 8]);
+    assert_eq!(lv1[hs[8]], 8);
+
+    {
+        let mut it = lv1.extract_if(|v| *v > 2);
+        assert_eq!(it.next(), Some(4));
+    }
+
+    assert_eq!(lv1.to_vec(), vec![0, 2, 6, 8]);
+    assert_eq!(lv1.iter().rev().copied().collect::<Vec<_>>(), 
+               vec![8, 6, 2, 0]);
+    assert_eq!(lv1.extract_if(|_| true).count(), 4);
+    assert!(lv1.is_empty());
+}
+
 #[test]
 fn from_array() {
     let lv1 = LinkedVector::from([1, 2, 3]);
@@ -236,6 +1062,30 @@ fn from_iter() {
     lv2.iter().zip(1..).for_each(|(a, b)| assert_eq!(a, &b));
 }
 
+#[test]
+fn fragmentation() {
+    let mut lv1 = LinkedVector::<i32>::new();
+    assert_eq!(lv1.fragmentation(), 0.0);
+
+    let h1 = lv1.push_back(1);
+    lv1.push_back(2);
+    lv1.push_front(0);
+
+    // Links 0 -> 1 and 1 -> 2 go backward then forward in the vector.
+    assert_eq!(lv1.fragmentation(), 0.5);
+
+    lv1.remove(h1);
+
+    assert_eq!(lv1.fragmentation(), 1.0 / 3.0 + 1.0);
+    assert_eq!(lv1.compact().fragmentation(), 0.0);
+
+    let mut lv2 = LinkedVector::from([1]);
+    lv2.push_back(2);
+    lv2.pop_back();
+
+    assert_eq!(lv2.fragmentation(), 0.5);
+}
+
 #[test]
 fn front() {
     let mut lv1 = LinkedVector::new();
@@ -339,6 +1189,94 @@ fn get_handle() {
     assert_eq!(lv1.handle(3), None);
 }
 
+#[test]
+fn handle_from_raw() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let hs      = lv1.handles().collect::<Vec<_>>();
+    let raws    = hs.iter().map(|h| h.into_raw()).collect::<Vec<_>>();
+
+    assert_eq!(raws.iter().map(|&r| r as u32).collect::<Vec<_>>(), 
+               vec![0, 1, 2]);
+
+    for (&h, &r) in hs.iter().zip(&raws) {
+        assert_eq!(lv1.handle_from_raw(r), Ok(h));
+    }
+    assert_eq!(lv1.handle_from_raw(u64::MAX), 
+               Err(LinkedVectorError::InvalidHandle));
+    assert_eq!(lv1.handle_from_raw(3), 
+               Err(LinkedVectorError::InvalidHandle));
+
+    lv1.remove(hs[1]);
+
+    assert_eq!(lv1.handle_from_raw(raws[1]), 
+               Err(LinkedVectorError::ExpiredHandle));
+
+    lv1.clear();
+
+    assert_eq!(lv1.handle_from_raw(raws[0]), 
+               Err(LinkedVectorError::EmptyList));
+}
+
+#[cfg(feature = "generational-handles")]
+#[test]
+fn handle_from_raw_recycled() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h2      = lv1.handle(1).unwrap();
+    let raw     = h2.into_raw();
+    lv1.remove(h2);
+    let h4      = lv1.push_back(4);
+
+    assert_eq!(raw >> 32, 0);
+    assert_eq!(h4.into_raw(), 1 << 32 | 1);
+    assert_eq!(lv1.handle_from_raw(raw), 
+               Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1.handle_from_raw(h4.into_raw()), Ok(h4));
+}
+
+#[cfg(feature = "checked-handles")]
+#[test]
+fn handle_from_raw_foreign() {
+    let lv1 = LinkedVector::from([1, 2, 3]);
+    let lv2 = LinkedVector::from([4, 5, 6]);
+    let h5  = lv2.handle(1).unwrap();
+
+    // The raw value doesn't carry the ID of the vector, so the handle 
+    // rendered by another vector is native to that vector instead.
+    let h2  = lv1.handle_from_raw(h5.into_raw()).unwrap();
+
+    assert_eq!(lv1[h2], 2);
+    assert_ne!(h2, h5);
+}
+
+#[test]
+#[cfg(all(feature = "generational-handles", target_pointer_width = "64"))]
+fn handle_from_raw_generation_wrap() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h2      = lv1.handle(1).unwrap();
+    let raw2    = h2.into_raw();
+
+    // Stand in for 2^32 recyclings of the node.
+    lv1.remove(h2);
+    lv1.vec[1].gen = (1 << 32) + 1;
+    let h4   = lv1.push_back(4);
+    let raw4 = h4.into_raw();
+
+    assert_eq!(raw4 >> 32, 1);
+    assert_eq!(lv1.handle_from_raw(raw4), Ok(h4));
+
+    // The low 32 bits of the old value's generation still differ.
+    assert_eq!(lv1.handle_from_raw(raw2), 
+               Err(LinkedVectorError::ExpiredHandle));
+
+    // Once they match again, the old value is taken for the new element.
+    lv1.remove(h4);
+    lv1.vec[1].gen = 1 << 32;
+    let h5 = lv1.push_back(5);
+
+    assert_eq!(lv1.try_get(h2), Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1.handle_from_raw(raw2), Ok(h5));
+}
+
 #[test]
 fn handles() {
     let mut lv1 = LinkedVector::new();
@@ -362,6 +1300,21 @@ fn handles() {
     assert!(it.next().is_none());
 }
 
+#[test]
+fn handles_range() {
+    let lv1 = LinkedVector::from([1, 2, 3, 4, 5]);
+    let hs  = lv1.handles().collect::<Vec<_>>();
+
+    assert_eq!(lv1.handles_range(hs[1]..hs[3]).collect::<Vec<_>>(), 
+               vec![hs[1], hs[2]]);
+    assert_eq!(lv1.handles_range(..=hs[1]).rev().collect::<Vec<_>>(), 
+               vec![hs[1], hs[0]]);
+    assert_eq!(lv1.handles_from(hs[3]).collect::<Vec<_>>(), 
+               vec![hs[3], hs[4]]);
+    assert_eq!(lv1.handles_from(hs[3]).len(), 2);
+    assert_eq!(lv1.handles_range(hs[1]..hs[3]).last(), Some(hs[2]));
+}
+
 #[test]
 fn hashing() {
     let mut map = HashMap::new();
@@ -471,6 +1424,29 @@ fn insert_before() {
     assert_eq!(lv1.len(), 4);
 }
 
+#[test]
+fn insert_expired_handle() {
+    let mut lv1 = LinkedVector::from([1]);
+    let h2 = lv1.push_back(2);
+    lv1.remove(h2);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        lv1.insert(h2, 9);
+    }));
+    assert!(result.is_err());
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        lv1.insert_after(h2, 9);
+    }));
+    assert!(result.is_err());
+
+    // The vector is left as it was, so the vacant node is still reused.
+    assert_eq!(lv1.len(), 1);
+    assert_eq!(lv1.vacant_count(), 1);
+    assert_eq!(lv1.push_back(3).0, h2.0);
+    assert_eq!(lv1.to_vec(), vec![1, 3]);
+}
+
 #[test]
 fn test_insertions_deletions_etc() {
     let mut lv1 = LinkedVector::new();
@@ -531,6 +1507,39 @@ fn into_iter_rev() {
     assert!(it.next().is_none());
 }
 
+#[test]
+fn into_iter_with_handles() {
+    let lv1 = LinkedVector::from([1, 2, 3, 4]);
+    let hs  = lv1.handles().collect::<Vec<_>>();
+    let mut it = lv1.into_iter_with_handles();
+
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.next(), Some((hs[0], 1)));
+    assert_eq!(it.next_back(), Some((hs[3], 4)));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.collect::<Vec<_>>(), vec![(hs[1], 2), (hs[2], 3)]);
+}
+
+#[test]
+fn into_iter_compaction_policy() {
+    let mut lv1 = LinkedVector::from_iter(0..10);
+    let mut lv2 = lv1.clone();
+    let hs = lv1.handles().collect::<Vec<_>>();
+
+    lv1.set_compaction_policy(Some(CompactionPolicy::new(0.25).min_nodes(0)));
+    lv2.set_compaction_policy(Some(CompactionPolicy::new(0.25).min_nodes(0)));
+
+    // The yielded handles are the ones the elements had before iterating.
+    let mut it = lv1.into_iter_with_handles();
+
+    assert_eq!(it.next(), Some((hs[0], 0)));
+    assert_eq!(it.next_back(), Some((hs[9], 9)));
+    assert_eq!(it.collect::<Vec<_>>(), 
+               hs[1..9].iter().copied().zip(1..9).collect::<Vec<_>>());
+    assert_eq!(lv2.into_iter().rev().collect::<Vec<_>>(), 
+               (0..10).rev().collect::<Vec<_>>());
+}
+
 #[test]
 fn is_empty() {
     let mut lv1 = LinkedVector::new();
@@ -601,6 +1610,89 @@ fn iter_mut_rev() {
     lv1.iter().zip((10..=12).rev()).for_each(|(a, b)| assert_eq!(a, &b));
 }
 
+#[test]
+fn iter_range() {
+    let lv1 = LinkedVector::from([1, 2, 3, 4, 5, 6]);
+    let hs  = lv1.handles().collect::<Vec<_>>();
+
+    assert_eq!(lv1.iter_range(hs[1]..hs[4]).copied().collect::<Vec<_>>(), 
+               vec![2, 3, 4]);
+    assert_eq!(lv1.iter_range(hs[1]..=hs[4]).copied().collect::<Vec<_>>(), 
+               vec![2, 3, 4, 5]);
+    assert_eq!(lv1.iter_range(hs[1]..=hs[4]).rev().copied()
+                  .collect::<Vec<_>>(), vec![5, 4, 3, 2]);
+    assert_eq!(lv1.iter_range(hs[2]..hs[2]).count(), 0);
+    assert_eq!(lv1.iter_range(hs[2]..=hs[2]).count(), 1);
+    assert_eq!(lv1.iter_range(..).len(), 6);
+    assert_eq!(lv1.iter_range(..hs[2]).last(), Some(&2));
+
+    let mut it = lv1.iter_range(hs[1]..=hs[3]);
+    assert_eq!(it.next(), Some(&2));
+    assert_eq!(it.next_back(), Some(&4));
+    assert_eq!(it.next(), Some(&3));
+    assert_eq!(it.next_back(), None);
+    assert_eq!(it.next(), None);
+
+    assert_eq!(lv1.iter_from(hs[4]).copied().collect::<Vec<_>>(), 
+               vec![5, 6]);
+    assert_eq!(lv1.iter_from(hs[4]).rev().copied().collect::<Vec<_>>(), 
+               vec![6, 5]);
+}
+
+#[test]
+#[should_panic]
+fn iter_range_bad_range() {
+    let lv1 = LinkedVector::from([1, 2, 3, 4]);
+    let hs  = lv1.handles().collect::<Vec<_>>();
+
+    lv1.iter_range(hs[3]..=hs[1]);
+}
+
+#[test]
+fn iter_range_mut() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5, 6]);
+    let hs = lv1.handles().collect::<Vec<_>>();
+
+    lv1.iter_range_mut(hs[1]..hs[3]).for_each(|v| *v *= 10);
+    lv1.iter_from_mut(hs[4]).rev().zip(100..).for_each(|(v, n)| *v = n);
+
+    assert_eq!(lv1.to_vec(), vec![1, 20, 30, 4, 101, 100]);
+}
+
+#[test]
+fn iter_with_handles() {
+    let lv1 = LinkedVector::from([1, 2, 3]);
+    let hs  = lv1.handles().collect::<Vec<_>>();
+
+    assert_eq!(lv1.iter_with_handles().collect::<Vec<_>>(), 
+               vec![(hs[0], &1), (hs[1], &2), (hs[2], &3)]);
+    assert_eq!(lv1.iter_with_handles().rev().collect::<Vec<_>>(), 
+               vec![(hs[2], &3), (hs[1], &2), (hs[0], &1)]);
+
+    let mut it = lv1.iter_with_handles();
+    assert_eq!(it.len(), 3);
+    it.next();
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.last(), Some((hs[2], &3)));
+}
+
+#[test]
+fn iter_mut_with_handles() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+
+    for (_, v) in lv1.iter_mut_with_handles().rev() {
+        *v *= 10;
+    }
+    let pairs = lv1.iter_mut_with_handles()
+                   .map(|(h, v)| (h, *v))
+                   .collect::<Vec<_>>();
+
+    for (h, v) in pairs {
+        assert_eq!(lv1[h], v);
+    }
+    assert_eq!(lv1.to_vec(), vec![10, 20, 30]);
+}
+
 #[test]
 fn len() {
     let mut lv1 = LinkedVector::new();
@@ -619,6 +1711,207 @@ fn len() {
     assert_eq!(lv1.len(), 0);
 }
 
+#[test]
+fn move_after() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5]);
+    let hs = lv1.handles().collect::<Vec<_>>();
+
+    lv1.move_after(hs[0], hs[2]);
+    assert_eq!(lv1.to_vec(), vec![2, 3, 1, 4, 5]);
+
+    lv1.move_after(hs[1], hs[4]);
+    assert_eq!(lv1.to_vec(), vec![3, 1, 4, 5, 2]);
+    assert_eq!(lv1.back_node(), Some(hs[1]));
+
+    lv1.move_after(hs[3], hs[3]);
+    assert_eq!(lv1.to_vec(), vec![3, 1, 4, 5, 2]);
+
+    assert_eq!(lv1.iter().rev().copied().collect::<Vec<_>>(), 
+               vec![2, 5, 4, 1, 3]);
+    assert_eq!(lv1[hs[0]], 1);
+    assert_eq!(lv1.len(), 5);
+}
+
+#[test]
+fn move_before() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5]);
+    let hs = lv1.handles().collect::<Vec<_>>();
+
+    lv1.move_before(hs[4], hs[0]);
+    assert_eq!(lv1.to_vec(), vec![5, 1, 2, 3, 4]);
+    assert_eq!(lv1.front_node(), Some(hs[4]));
+
+    lv1.move_before(hs[4], hs[3]);
+    assert_eq!(lv1.to_vec(), vec![1, 2, 3, 5, 4]);
+    assert_eq!(lv1.front_node(), Some(hs[0]));
+    assert_eq!(lv1.back_node(), Some(hs[3]));
+
+    assert_eq!(lv1.iter().rev().copied().collect::<Vec<_>>(), 
+               vec![4, 5, 3, 2, 1]);
+}
+
+#[test]
+fn move_to_front_back() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let hs = lv1.handles().collect::<Vec<_>>();
+
+    lv1.move_to_front(hs[2]);
+    assert_eq!(lv1.to_vec(), vec![3, 1, 2]);
+
+    lv1.move_to_front(hs[2]);
+    assert_eq!(lv1.to_vec(), vec![3, 1, 2]);
+
+    lv1.move_to_back(hs[2]);
+    assert_eq!(lv1.to_vec(), vec![1, 2, 3]);
+
+    lv1.move_to_back(hs[0]);
+    assert_eq!(lv1.to_vec(), vec![2, 3, 1]);
+    assert_eq!(lv1.iter().rev().copied().collect::<Vec<_>>(), vec![1, 3, 2]);
+
+    let mut lv2 = LinkedVector::new();
+    let h = lv2.push_back(1);
+    lv2.move_to_back(h);
+    lv2.move_to_front(h);
+    assert_eq!(lv2.to_vec(), vec![1]);
+    assert_eq!(lv2.back_node(), Some(h));
+}
+
+#[test]
+fn new_indexed() {
+    let mut lv1 = LinkedVector::<i32, u16>::new_indexed();
+    let h1 = lv1.push_back(1);
+    let h2 = lv1.push_back(2);
+    let h3 = lv1.push_back(3);
+    lv1.remove(h2);
+    let h4 = lv1.insert(h3, 4);
+    assert_eq!(lv1.to_vec(), vec![1, 4, 3]);
+    assert_eq!(lv1.handles().collect::<Vec<_>>(), vec![h1, h4, h3]);
+
+    let mut lv2 = LinkedVector::<i32, u16>::with_capacity_indexed(2);
+    lv2.extend([5, 6]);
+    lv1.append(&mut lv2);
+    assert_eq!(lv1.to_vec(), vec![1, 4, 3, 5, 6]);
+
+    let lv3 = lv1.compact();
+    assert_eq!(lv3.to_vec(), vec![1, 4, 3, 5, 6]);
+
+    assert!(size_of::<Node<u32, u32>>() < size_of::<Node<u32, usize>>());
+}
+
+#[test]
+fn new_indexed_handle_size() {
+    // Handles hold an index, a generation of the index type, and the ID of 
+    // the vector with "checked-handles".
+    #[cfg(feature = "checked-handles")]
+    assert_eq!(size_of::<HNode<u32>>(), 8 + size_of::<usize>());
+
+    #[cfg(all(feature = "generational-handles", 
+              not(feature = "checked-handles")))]
+    assert_eq!(size_of::<HNode<u32>>(), 8);
+
+    #[cfg(not(feature = "generational-handles"))]
+    assert_eq!(size_of::<HNode<u32>>(), 4);
+}
+
+#[test]
+#[cfg(feature = "generational-handles")]
+fn new_indexed_generation_wrap() {
+    let mut lv1 = LinkedVector::<i32, u16>::new_indexed();
+    let h1 = lv1.push_back(1);
+
+    // The generation of the node wraps around instead of overflowing.
+    for _ in 0..u16::MAX {
+        lv1.pop_back();
+        lv1.push_back(2);
+    }
+    assert_eq!(lv1.try_get(h1), Err(LinkedVectorError::ExpiredHandle));
+
+    lv1.pop_back();
+    let h2 = lv1.push_back(3);
+
+    assert_eq!(h2, h1);
+    assert_eq!(lv1[h1], 3);
+}
+
+#[test]
+#[should_panic]
+fn new_indexed_overflow() {
+    let mut lv1 = LinkedVector::<u8, u16>::new_indexed();
+    for _ in 0..u16::MAX {
+        lv1.push_back(0);
+    }
+    assert_eq!(lv1.len(), u16::MAX as usize);
+    lv1.push_back(0);
+}
+
+#[test]
+fn paged_linked_vector() {
+    let mut lv = PagedLinkedVector::<i32, usize, 4>::new_paged();
+    let h1 = lv.push_back(1);
+    let p1 = &lv[h1] as *const i32;
+
+    lv.extend(2..=10);
+
+    assert_eq!(lv.capacity(), 12);
+    assert_eq!(&lv[h1] as *const i32, p1);
+    assert_eq!(lv.to_vec(), (1..=10).collect::<Vec<_>>());
+
+    let h5 = lv.handle(4).unwrap();
+    lv.remove(h1);
+    lv.remove(h5);
+    lv.push_front(0);
+
+    let remap = lv.compact_in_place();
+
+    assert_eq!(lv.to_vec(), vec![0, 2, 3, 4, 6, 7, 8, 9, 10]);
+    assert_eq!(lv.handles().collect::<Vec<_>>(), 
+               (0..9).map(|i| lv.handle(i).unwrap()).collect::<Vec<_>>());
+
+    lv.sort_by_key(|&v| Reverse(v));
+
+    assert_eq!(lv.to_vec(), vec![10, 9, 8, 7, 6, 4, 3, 2, 0]);
+
+    let h6 = lv.handle(4).unwrap();
+    let lv2 = lv.split_off(h6);
+
+    assert_eq!(lv.to_vec(), vec![10, 9, 8, 7]);
+    assert_eq!(lv2.to_vec(), vec![6, 4, 3, 2, 0]);
+    assert_eq!(lv2.clone(), lv2);
+
+    lv.clear();
+    lv.extend([1, 2]);
+
+    assert_eq!(lv.capacity(), 12);
+    assert_eq!(lv.to_vec(), vec![1, 2]);
+}
+
+#[test]
+fn paged_linked_vector_reserve() {
+    let rc = Rc::new(0);
+    let mut lv = PagedLinkedVector::<Rc<i32>, u32, 8>::new_paged();
+
+    assert_eq!(lv.capacity(), 0);
+    assert!(lv.try_reserve(9).is_ok());
+    assert_eq!(lv.capacity(), 16);
+    assert!(lv.try_reserve(usize::MAX).is_err());
+
+    lv.reserve(20);
+
+    assert_eq!(lv.capacity(), 24);
+
+    for _ in 0..20 {
+        lv.push_back(rc.clone());
+    }
+    let h1 = lv.front_node().unwrap();
+    lv.remove(h1);
+
+    assert_eq!(Rc::strong_count(&rc), 20);
+
+    drop(lv);
+
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
 #[test]
 fn pop_back() {
     let mut lv1 = LinkedVector::new();
@@ -667,6 +1960,166 @@ fn push_front() {
     assert_eq!(lv1.len(), 3);
 }
 
+#[test]
+fn recycle_lowest_index() {
+    let mut lv = LinkedVector::from([0, 1, 2, 3, 4, 5]);
+    let hs = lv.handles().collect::<Vec<_>>();
+
+    lv.set_recycle_policy(RecyclePolicy::LowestIndex);
+
+    for i in [4, 1, 5, 2] {
+        lv.remove(hs[i]);
+    }
+    let indices = (0..4).map(|i| lv.push_front(i).0)
+                        .collect::<Vec<_>>();
+
+    assert_eq!(indices, vec![1, 2, 4, 5]);
+    assert_eq!(lv.to_vec(), vec![3, 2, 1, 0, 0, 3]);
+}
+
+#[test]
+fn recycle_lowest_index_bulk_removal() {
+    let mut lv1 = LinkedVector::from_iter(0..10);
+    let hs = lv1.handles().collect::<Vec<_>>();
+
+    lv1.set_recycle_policy(RecyclePolicy::LowestIndex);
+    lv1.remove(hs[5]);
+    lv1.retain(|&v| v % 3 != 0);
+
+    let lv2 = lv1.split_off(hs[7]);
+    let indices = (0..6).map(|i| lv1.push_back(i).0)
+                        .collect::<Vec<_>>();
+
+    assert_eq!(indices, vec![0, 3, 5, 6, 7, 8]);
+    assert_eq!(lv1.to_vec(), vec![1, 2, 4, 0, 1, 2, 3, 4, 5]);
+    assert_eq!(lv2.to_vec(), vec![7, 8]);
+}
+
+#[test]
+fn recycle_lowest_index_churn() {
+    let mut lv = LinkedVector::from_iter(0..64);
+    let mut seed = 54321u32;
+    let mut rand = |n: usize| {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        (seed >> 16) as usize % n
+    };
+    lv.set_recycle_policy(RecyclePolicy::LowestIndex);
+
+    // Removals and additions are interleaved, so nodes released since the
+    // last addition are merged into a list that's already in order.
+    for i in 0..1000 {
+        for _ in 0..rand(4) {
+            if !lv.is_empty() {
+                let hnode = lv.handle(rand(lv.len())).unwrap();
+                lv.remove(hnode);
+            }
+        }
+        for _ in 0..rand(4) {
+            let lowest = (0..lv.vec.len()).find(|&j| !lv.vec[j].is_occupied());
+            let hnode  = lv.push_back(i);
+            assert_eq!(hnode.0, lowest.unwrap_or(lv.vec.len() - 1));
+        }
+    }
+    assert_eq!(lv.len() + lv.vacant_count(), lv.vec.len());
+}
+
+#[test]
+fn recycle_near_neighbor() {
+    let mut lv = LinkedVector::from([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let hs = lv.handles().collect::<Vec<_>>();
+
+    lv.set_recycle_policy(RecyclePolicy::NearNeighbor);
+
+    for i in [0, 9, 4, 6] {
+        lv.remove(hs[i]);
+    }
+    assert_eq!(lv.insert(hs[7], 10).0, 6);
+    assert_eq!(lv.insert(hs[3], 11).0, 4);
+    assert_eq!(lv.push_back(12).0, 9);
+    assert_eq!(lv.push_front(13).0, 0);
+    assert_eq!(lv.to_vec(), vec![13, 1, 2, 11, 3, 5, 10, 7, 8, 12]);
+}
+
+#[test]
+fn recycle_policy_switch() {
+    let mut lv1 = LinkedVector::from([0, 1, 2, 3, 4, 5]);
+    let hs = lv1.handles().collect::<Vec<_>>();
+
+    for i in [3, 0, 5] {
+        lv1.remove(hs[i]);
+    }
+    lv1.set_recycle_policy(RecyclePolicy::LowestIndex);
+
+    let lv2 = lv1.split_off(hs[4]);
+    let mut lv3 = LinkedVector::from([6, 7]);
+    let h6 = lv3.front_node().unwrap();
+    lv3.remove(h6);
+
+    assert_eq!(lv2.recycle_policy(), RecyclePolicy::LowestIndex);
+    assert_eq!(lv1.push_back(8).0, 0);
+
+    // Appending joins the recycle lists, which is then sorted again.
+    lv1.append(&mut lv3);
+
+    let indices = (0..4).map(|i| lv1.push_back(i).0)
+                        .collect::<Vec<_>>();
+
+    assert_eq!(indices, vec![3, 4, 5, 6]);
+    assert_eq!(lv1.to_vec(), vec![1, 2, 8, 7, 0, 1, 2, 3]);
+
+    lv1.set_recycle_policy(RecyclePolicy::Lifo);
+
+    assert_eq!(lv1.recycle_policy(), RecyclePolicy::Lifo);
+    assert_eq!(lv2.to_vec(), vec![4]);
+}
+
+#[test]
+fn recycle_policy_churn() {
+    let policies = [RecyclePolicy::Lifo, 
+                    RecyclePolicy::LowestIndex, 
+                    RecyclePolicy::NearNeighbor];
+    for policy in policies {
+        let mut lv = LinkedVector::new();
+        let mut model = Vec::new();
+        let mut seed = 12345u32;
+        let mut rand = |n: usize| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 16) as usize % n
+        };
+        lv.set_recycle_policy(policy);
+
+        for i in 0..2000 {
+            if model.is_empty() || rand(3) > 0 {
+                let at = rand(model.len() + 1);
+                if at == model.len() {
+                    lv.push_back(i);
+                } else {
+                    lv.insert(lv.handle(at).unwrap(), i);
+                }
+                model.insert(at, i);
+            } else {
+                let at = rand(model.len());
+                let hnode = lv.handle(at).unwrap();
+                assert_eq!(lv.try_remove(hnode), Ok(model.remove(at)));
+            }
+        }
+        assert_eq!(lv.to_vec(), model);
+        assert_eq!(lv.len(), model.len());
+    }
+}
+
+#[test]
+fn reserve() {
+    let mut lv = LinkedVector::from([1, 2, 3]);
+
+    lv.reserve(10);
+    assert!(lv.capacity() >= 13);
+
+    lv.reserve_exact(20);
+    assert!(lv.capacity() >= 23);
+    assert_eq!(lv.to_vec(), vec![1, 2, 3]);
+}
+
 #[test]
 fn remove() {
     let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5, 6, 7, 8, 9]);
@@ -681,6 +2134,56 @@ fn remove() {
     assert_eq!(lv1.to_vec(), vec![1, 2, 3, 4, 5, 6, 8, 9]);
 }
 
+#[test]
+fn retain() {
+    let mut lv1 = LinkedVector::new();
+    let hs = (0..10).map(|i| lv1.push_back(i)).collect::<Vec<_>>();
+
+    lv1.retain(|&v| v % 3 == 1);
+
+    assert_eq!(lv1.to_vec(), vec![1, 4, 7]);
+    assert_eq!(lv1.len(), 3);
+    assert_eq!(lv1[hs[4]], 4);
+    assert_eq!(lv1.iter().rev().copied().collect::<Vec<_>>(), vec![7, 4, 1]);
+
+    lv1.retain(|_| false);
+    assert!(lv1.is_empty());
+
+    lv1.push_back(42);
+    assert_eq!(lv1.to_vec(), vec![42]);
+}
+
+#[test]
+fn retain_mut() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5]);
+
+    lv1.retain_mut(|v| { *v += 1; *v % 2 == 0 });
+
+    assert_eq!(lv1.to_vec(), vec![2, 4, 6]);
+}
+
+#[test]
+fn retain_with_handle() {
+    let mut lv1 = LinkedVector::new();
+    let mut map = HashMap::new();
+
+    for i in 0..6 {
+        map.insert(i, lv1.push_back(i));
+    }
+    lv1.retain_with_handle(|h, &v| {
+        if v < 3 {
+            assert_eq!(map.remove(&v), Some(h));
+            false
+        } else {
+            true
+        }
+    });
+    assert_eq!(lv1.to_vec(), vec![3, 4, 5]);
+    for (k, h) in map {
+        assert_eq!(lv1[h], k);
+    }
+}
+
 #[test]
 fn sort() {
     let mut lv1 = LinkedVector::from([2, 1, 6, 7, 4, 8, 5, 3]);
@@ -773,6 +2276,59 @@ fn sort_by_key() {
     assert_eq!(lv1.to_vec(), vec![8, 7, 6, 5, 4, 3, 2, 1]);    
 }
 
+#[test]
+fn split_off() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5, 6]);
+    let hs = lv1.handles().collect::<Vec<_>>();
+
+    let mut lv2 = lv1.split_off(hs[3]);
+
+    assert_eq!(lv1.to_vec(), vec![1, 2, 3]);
+    assert_eq!(lv2.to_vec(), vec![4, 5, 6]);
+    assert_eq!(lv1.len(), 3);
+    assert_eq!(lv2.len(), 3);
+    assert_eq!(lv1.back_node(), Some(hs[2]));
+    assert_eq!(lv1.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+    lv1.push_back(7);
+    assert_eq!(lv1.to_vec(), vec![1, 2, 3, 7]);
+
+    let lv3 = lv2.split_off(lv2.front_node().unwrap());
+
+    assert!(lv2.is_empty());
+    assert_eq!(lv2.front_node(), None);
+    assert_eq!(lv3.to_vec(), vec![4, 5, 6]);
+}
+
+#[test]
+#[cfg(feature = "generational-handles")]
+fn split_off_expires_moved_handles() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4]);
+    let h1  = lv1.handle(0).unwrap();
+    let h3  = lv1.handle(2).unwrap();
+    let lv2 = lv1.split_off(h3);
+
+    assert_eq!(lv1.try_get(h1), Ok(&1));
+    assert_eq!(lv1.try_get(h3), Err(LinkedVectorError::ExpiredHandle));
+}
+
+#[test]
+fn split_off_after() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4]);
+    let h2 = lv1.handle(1).unwrap();
+    let h4 = lv1.handle(3).unwrap();
+
+    let lv2 = lv1.split_off_after(h2);
+
+    assert_eq!(lv1.to_vec(), vec![1, 2]);
+    assert_eq!(lv2.to_vec(), vec![3, 4]);
+
+    let lv3 = lv1.split_off_after(h2);
+
+    assert!(lv3.is_empty());
+    assert_eq!(lv1.to_vec(), vec![1, 2]);
+}
+
 #[test]
 fn to_vec() {
     let mut lv1 = LinkedVector::new();
@@ -791,4 +2347,136 @@ fn with_capacity() {
     lv1.push_back(3);
     assert_eq!(lv1.capacity(), 10);
     assert_eq!(lv1.len(), 3);
-}
\ No newline at end of file
+}
+#[test]
+fn try_get() {
+    let mut lv1 = LinkedVector::new();
+    let h1 = lv1.push_back(1);
+    let h2 = lv1.push_back(2);
+
+    assert_eq!(lv1.try_get(h1), Ok(&1));
+    assert_eq!(lv1.try_get(HNode::default()), 
+               Err(LinkedVectorError::InvalidHandle));
+
+    lv1.remove(h2);
+
+    assert_eq!(lv1.try_get(h2), Err(LinkedVectorError::ExpiredHandle));
+
+    *lv1.try_get_mut(h1).unwrap() = 42;
+
+    assert_eq!(lv1.try_get(h1), Ok(&42));
+}
+
+#[test]
+#[cfg(feature = "generational-handles")]
+fn try_get_recycled() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h2 = lv1.handle(1).unwrap();
+
+    lv1.remove(h2);
+    lv1.push_back(4); // This will recycle node pointed to by h2.
+
+    assert_eq!(lv1.try_get(h2), Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1.try_remove(h2), Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1.to_vec(), vec![1, 3, 4]);
+}
+
+#[test]
+#[cfg(feature = "checked-handles")]
+fn try_get_foreign() {
+    let lv1 = LinkedVector::from([1, 2, 3]);
+    let lv2 = LinkedVector::from([1, 2, 3]);
+    let h1  = lv1.front_node().unwrap();
+
+    assert_eq!(lv2.try_get(h1), Err(LinkedVectorError::ForeignHandle));
+}
+
+#[test]
+fn try_insert() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h1 = lv1.front_node().unwrap();
+    let h3 = lv1.back_node().unwrap();
+
+    lv1.try_insert(h1, 0).unwrap();
+    lv1.try_insert_after(h3, 4).unwrap();
+
+    assert_eq!(lv1.to_vec(), vec![0, 1, 2, 3, 4]);
+
+    lv1.remove(h3);
+
+    assert_eq!(lv1.try_insert(h3, 5).unwrap_err().into_parts(), 
+               (5, LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1.try_insert_after(h3, 6).unwrap_err().into_parts(), 
+               (6, LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1.to_vec(), vec![0, 1, 2, 4]);
+}
+
+#[test]
+fn try_insert_full_array() {
+    let mut lv1 = ArrayLinkedVector::<String, 2>::new_array();
+    let h1 = lv1.push_back("one".to_string());
+    let h2 = lv1.push_back("two".to_string());
+
+    let err = lv1.try_insert(h1, "three".to_string()).unwrap_err();
+
+    assert!(matches!(err.error(), LinkedVectorError::ReserveFailed(_)));
+    assert_eq!(err.into_inner(), "three");
+
+    let (value, err) = lv1.try_insert_after(h2, "four".to_string())
+                          .unwrap_err()
+                          .into_parts();
+
+    assert_eq!(value, "four");
+    assert!(matches!(err, LinkedVectorError::ReserveFailed(_)));
+    assert_eq!(lv1.to_vec(), vec!["one", "two"]);
+
+    lv1.remove(h2);
+
+    assert!(lv1.try_insert(h1, "five".to_string()).is_ok());
+    assert_eq!(lv1.to_vec(), vec!["five", "one"]);
+}
+
+#[test]
+fn try_remove() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h2 = lv1.handle(1).unwrap();
+
+    assert_eq!(lv1.try_remove(h2), Ok(2));
+    assert_eq!(lv1.try_remove(h2), Err(LinkedVectorError::ExpiredHandle));
+    assert_eq!(lv1.to_vec(), vec![1, 3]);
+}
+
+#[test]
+fn try_reserve() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+
+    assert!(lv1.try_reserve(10).is_ok());
+    assert!(lv1.capacity() >= 13);
+    assert!(lv1.try_reserve(usize::MAX).is_err());
+    assert!(lv1.try_push_back(4).is_ok());
+    assert_eq!(lv1.to_vec(), vec![1, 2, 3, 4]);
+
+    let mut lv2 = ArrayLinkedVector::<i32, 3>::new_array();
+    let h1 = lv2.try_push_back(1).unwrap();
+
+    assert!(lv2.try_reserve(2).is_ok());
+    assert!(lv2.try_reserve(3).is_err());
+
+    lv2.extend([2, 3]);
+
+    let err = lv2.try_insert(h1, 0).unwrap_err();
+
+    assert!(matches!(err.error(), LinkedVectorError::ReserveFailed(_)));
+    assert!(lv2.try_insert_after(h1, 0).is_err());
+
+    let (value, err) = lv2.try_push_back(4).unwrap_err().into_parts();
+
+    assert_eq!(value, 4);
+    assert_eq!(LinkedVectorError::from(err), 
+               LinkedVectorError::from(lv2.try_push_front(5).unwrap_err()));
+
+    lv2.remove(h1);
+
+    assert!(lv2.try_push_front(0).is_ok());
+    assert_eq!(lv2.to_vec(), vec![0, 2, 3]);
+}
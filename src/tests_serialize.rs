@@ -0,0 +1,283 @@
+#![allow(unused_variables)]
+
+use serde::Deserialize;
+use serde::de::value::{Error, SeqDeserializer};
+use serde_json::value::Serializer;
+use serde_json::{json, Value};
+
+use crate::linked_vector::*;
+use crate::serialize::layout;
+
+#[cfg(feature = "generational-handles")]
+use crate::error::*;
+
+#[test]
+fn serialize() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h0 = lv1.push_front(0);
+    lv1.remove(h0);
+    lv1.push_front(4);
+
+    let json = serde_json::to_string(&lv1).unwrap();
+
+    assert_eq!(json, "[4,1,2,3]");
+
+    let empty = LinkedVector::<i32>::new();
+
+    assert_eq!(serde_json::to_string(&empty).unwrap(), "[]");
+}
+
+#[test]
+fn deserialize() {
+    let lv1: LinkedVector<String> = 
+        serde_json::from_str(r#"["a", "b", "c"]"#).unwrap();
+
+    assert_eq!(lv1.len(), 3);
+    assert_eq!(lv1.to_vec(), vec!["a", "b", "c"]);
+    assert_eq!(lv1.iter().rev().collect::<Vec<_>>(), vec!["c", "b", "a"]);
+
+    let res = serde_json::from_str::<LinkedVector<i32>>(r#"{"a": 1}"#);
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn serialize_round_trip() {
+    let mut lv1 = LinkedVector::from([5, 3, 4, 1, 2]);
+    lv1.sort();
+    lv1.pop_front();
+    lv1.push_back(6);
+
+    let json = serde_json::to_string(&lv1).unwrap();
+    let lv2: LinkedVector<i32> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(lv1, lv2);
+}
+
+#[test]
+fn deserialize_reserves_capacity() {
+    let seq = SeqDeserializer::<_, Error>::new(vec![1, 2, 3].into_iter());
+    let lv1 = LinkedVector::<i32>::deserialize(seq).unwrap();
+
+    assert_eq!(lv1.to_vec(), vec![1, 2, 3]);
+    assert_eq!(lv1.capacity(), 3);
+}
+
+#[test]
+fn serialize_handle() {
+    let lv1 = LinkedVector::from([1, 2, 3]);
+    let h2  = lv1.handle(1).unwrap();
+
+    let json = serde_json::to_string(&h2).unwrap();
+    let h2b: HNode = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(h2, h2b);
+
+    let json = serde_json::to_string(&<HNode>::default()).unwrap();
+    let hbad: HNode = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(hbad, HNode::default());
+
+    let ht: TypedHNode<i32> = h2.into();
+    let json = serde_json::to_string(&ht).unwrap();
+    let htb: TypedHNode<i32> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(ht, htb);
+    assert_eq!(json, serde_json::to_string(&h2).unwrap());
+}
+
+#[test]
+fn layout_round_trip() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4, 5]);
+    let hs      = lv1.handles().collect::<Vec<_>>();
+    lv1.remove(hs[1]);
+    lv1.remove(hs[3]);
+    lv1.move_to_front(hs[4]);
+
+    let value   = layout::serialize(&lv1, Serializer).unwrap();
+    let mut lv2: LinkedVector<i32> = layout::deserialize(value).unwrap();
+
+    assert_eq!(lv2.to_vec(), vec![5, 1, 3]);
+    assert_eq!(lv2.iter().rev().copied().collect::<Vec<_>>(), vec![3, 1, 5]);
+    assert_eq!(lv2[hs[0]], 1);
+    assert_eq!(lv2[hs[2]], 3);
+    assert_eq!(lv2[hs[4]], 5);
+    assert_eq!(lv2.capacity(), 5);
+
+    // The recycled nodes are reused in the same order as in the original.
+    let h6a = lv1.push_back(6);
+    let h6b = lv2.push_back(6);
+
+    assert_eq!(h6a, h6b);
+    assert_eq!(lv2[h6a], 6);
+    assert_eq!(lv2.capacity(), 5);
+}
+
+#[test]
+fn layout_empty() {
+    let lv1 = LinkedVector::<i32>::new();
+
+    let value = layout::serialize(&lv1, Serializer).unwrap();
+    let lv2: LinkedVector<i32> = layout::deserialize(value).unwrap();
+
+    assert!(lv2.is_empty());
+    assert_eq!(lv2.front_node(), None);
+}
+
+#[cfg(feature = "generational-handles")]
+#[test]
+fn layout_expired_handles() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h2      = lv1.handle(1).unwrap();
+    lv1.remove(h2);
+    lv1.push_back(4);
+
+    let value = layout::serialize(&lv1, Serializer).unwrap();
+    let lv2: LinkedVector<i32> = layout::deserialize(value).unwrap();
+
+    assert_eq!(lv2.try_get(h2), Err(LinkedVectorError::ExpiredHandle));
+}
+
+#[cfg(feature = "generational-handles")]
+#[test]
+fn layout_keeps_floor() {
+    let mut lv1 = LinkedVector::from([1, 2, 3]);
+    let h3      = lv1.back_node().unwrap();
+    lv1.clear();
+
+    let mut value = layout::serialize(&lv1, Serializer).unwrap();
+    let mut lv2: LinkedVector<i32> = layout::deserialize(value.clone())
+                                     .unwrap();
+    lv2.extend([4, 5, 6]);
+
+    assert_eq!(lv2.try_get(h3), Err(LinkedVectorError::ExpiredHandle));
+
+    // Layouts written without the floor are still accepted.
+    value.as_object_mut().unwrap().remove("floor");
+
+    assert!(layout::deserialize::<i32, usize, Vec<_>, _>(value).is_ok());
+}
+
+#[cfg(feature = "checked-handles")]
+#[test]
+fn layout_keeps_id() {
+    let lv1 = LinkedVector::from([1, 2, 3]);
+    let h1  = lv1.front_node().unwrap();
+
+    let value = layout::serialize(&lv1, Serializer).unwrap();
+    let lv2: LinkedVector<i32> = layout::deserialize(value.clone()).unwrap();
+    let lv3   = serde_json::from_value::<LinkedVector<i32>>(
+                    serde_json::to_value(&lv1).unwrap()).unwrap();
+
+    assert_eq!(lv2.try_get(h1), Ok(&1));
+    assert_eq!(lv3.try_get(h1), Err(LinkedVectorError::ForeignHandle));
+}
+
+#[cfg(feature = "checked-handles")]
+#[test]
+fn layout_reserves_id() {
+    let lv1 = LinkedVector::from([1, 2, 3]);
+    let h1  = lv1.front_node().unwrap();
+
+    // As if written by another process that had given out more IDs.
+    let mut value = layout::serialize(&lv1, Serializer).unwrap();
+    value["id"]   = json!(usize::MAX / 2);
+
+    let lv2: LinkedVector<i32> = layout::deserialize(value).unwrap();
+    let lv3 = LinkedVector::from([1, 2, 3]);
+
+    assert!(lv3.id > usize::MAX / 2);
+    assert_eq!(lv2.try_get(h1), Err(LinkedVectorError::ForeignHandle));
+    assert_eq!(lv2.try_get(lv3.front_node().unwrap()), 
+               Err(LinkedVectorError::ForeignHandle));
+}
+
+#[test]
+fn layout_index_type() {
+    let mut lv1 = LinkedVector::<i32, u16>::new_indexed();
+    let h1 = lv1.push_back(1);
+    let h2 = lv1.push_back(2);
+    let h3 = lv1.push_back(3);
+    lv1.remove(h2);
+
+    let value   = layout::serialize(&lv1, Serializer).unwrap();
+    let mut lv2: LinkedVector<i32, u16> = 
+        layout::deserialize(value.clone()).unwrap();
+
+    assert_eq!(lv2.to_vec(), vec![1, 3]);
+    assert_eq!(lv2[h3], 3);
+    assert_eq!(lv1.push_back(4), lv2.push_back(4));
+
+    // The format doesn't depend on the index type.
+    let lv3: LinkedVector<i32> = layout::deserialize(value.clone()).unwrap();
+    assert_eq!(lv3.to_vec(), vec![1, 3]);
+
+    let json = serde_json::to_string(&h3).unwrap();
+    let h3b: HNode<u16> = serde_json::from_str(&json).unwrap();
+    assert_eq!(h3, h3b);
+
+    let res = serde_json::from_str::<HNode<u16>>("[70000, 0, null]");
+    assert!(res.is_err());
+
+    // Generations are kept in the index type too.
+    let mut value = value;
+    value["nodes"][1][3] = json!(70000);
+
+    assert!(layout::deserialize::<i32, usize, Vec<_>, _>(value.clone())
+            .is_ok());
+
+    let res = layout::deserialize::<i32, u16, Vec<_>, _>(value);
+    assert!(res.unwrap_err().to_string().contains("generation exceeds"));
+
+    #[cfg(feature = "generational-handles")]
+    assert!(serde_json::from_str::<HNode<u16>>("[0, 70000, null]").is_err());
+}
+
+#[test]
+fn layout_rejects_corruption() {
+    let mut lv1 = LinkedVector::from([1, 2, 3, 4]);
+    let h3      = lv1.handle(2).unwrap();
+    lv1.remove(h3);
+
+    let value = layout::serialize(&lv1, Serializer).unwrap();
+
+    let corrupt = |f: &dyn Fn(&mut Value)| {
+        let mut value = value.clone();
+        f(&mut value);
+        layout::deserialize::<i32, usize, Vec<_>, _>(value).unwrap_err()
+              .to_string()
+    };
+    // Sanity check: the unaltered layout is accepted.
+    assert!(layout::deserialize::<i32, usize, Vec<_>, _>(value.clone())
+            .is_ok());
+
+    let err = corrupt(&|v| v["len"] = json!(5));
+    assert!(err.contains("length exceeds"));
+
+    let err = corrupt(&|v| v["len"] = json!(2));
+    assert!(err.contains("tail links"));
+
+    let err = corrupt(&|v| v["nodes"][0][1] = json!(9));
+    assert!(err.contains("out of bounds"));
+
+    let err = corrupt(&|v| v["recyc"] = json!(0));
+    assert!(err.contains("more than once"));
+
+    let err = corrupt(&|v| v["nodes"][1][2] = json!(3));
+    assert!(err.contains("previous link"));
+
+    let err = corrupt(&|v| v["nodes"][0][2] = json!(1));
+    assert!(err.contains("head doesn't link"));
+
+    let err = corrupt(&|v| v["nodes"][2][0] = json!(3));
+    assert!(err.contains("occupied node"));
+
+    let err = corrupt(&|v| v["recyc"] = json!(null));
+    assert!(err.contains("unreachable"));
+
+    let err = corrupt(&|v| v["head"] = json!(null));
+    assert!(err.contains("without a head"));
+
+    let err = corrupt(&|v| v["nodes"][1][0] = json!(null));
+    assert!(err.contains("vacant node"));
+}